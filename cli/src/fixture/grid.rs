@@ -0,0 +1,79 @@
+use super::enum_value;
+use serde::Deserialize;
+use wayfinder_core::{
+    enums::{Grid, GridDiagonals},
+    grids::{GridlessGrid, HexagonalGrid, SquareGrid},
+    types::Rectangle,
+};
+
+/// The grid of the scene, with the same fields as `canvas.grid`
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GridSource {
+    pub r#type: i32,
+    pub size: i32,
+    pub distance: f64,
+    #[serde(deserialize_with = "enum_value", default = "equidistant_diagonals")]
+    pub diagonals: GridDiagonals,
+    #[serde(default)]
+    pub size_x: Option<f64>,
+    #[serde(default)]
+    pub size_y: Option<f64>,
+    #[serde(default)]
+    pub columns: Option<bool>,
+    #[serde(default)]
+    pub even: Option<bool>,
+}
+
+fn equidistant_diagonals() -> GridDiagonals {
+    GridDiagonals::Equidistant
+}
+
+/// Fails with the type of the grid if it isn't one of Foundry's grid types
+impl TryFrom<GridSource> for Grid {
+    type Error = i32;
+
+    fn try_from(source: GridSource) -> Result<Self, Self::Error> {
+        let GridSource { r#type, size, distance, diagonals, size_x, size_y, columns, even } = source;
+
+        let grid = match r#type {
+            0 => Grid::Gridless(GridlessGrid { size, distance }),
+            1 => Grid::Square(SquareGrid { size, distance, diagonals }),
+            2..=5 => {
+                // The type is one of HEXODDR, HEXEVENR, HEXODDQ, or HEXEVENQ
+                let columns = columns.unwrap_or(r#type >= 4);
+                let even = even.unwrap_or(r#type % 2 == 1);
+
+                // Foundry measures hexagons by the distance between opposite sides
+                let size = size as f64;
+                let long = size * 2.0 / 3.0_f64.sqrt();
+
+                Grid::Hexagonal(HexagonalGrid {
+                    size: size as i32,
+                    distance,
+                    size_x: size_x.unwrap_or(if columns { long } else { size }),
+                    size_y: size_y.unwrap_or(if columns { size } else { long }),
+                    columns,
+                    even,
+                })
+            }
+            type_ => return Err(type_),
+        };
+
+        Ok(grid)
+    }
+}
+
+#[derive(Deserialize)]
+pub struct RectangleSource {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl From<RectangleSource> for Rectangle {
+    fn from(RectangleSource { x, y, width, height }: RectangleSource) -> Self {
+        Rectangle::new(x, y, width, height)
+    }
+}
@@ -0,0 +1,180 @@
+mod grid;
+mod options;
+mod region;
+mod scene;
+mod token;
+mod wall;
+
+pub use self::grid::GridSource;
+pub use self::grid::RectangleSource;
+pub use self::options::ElevationWindowSource;
+pub use self::options::GridMeasurePathResultSource;
+pub use self::options::ObserverSource;
+pub use self::options::OptionsSource;
+pub use self::options::SneakSource;
+pub use self::region::RegionShapeSource;
+pub use self::region::RegionSource;
+pub use self::scene::SceneExport;
+pub use self::token::TokenSource;
+pub use self::token::WaypointSource;
+pub use self::wall::WallSource;
+
+use serde::{de::Error as _, Deserialize, Deserializer};
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+};
+use wayfinder_core::{
+    enums::Grid,
+    types::{
+        ElevatedPoint, FindMovementPathOptions, GridMeasurePathResult, Point, Rectangle, Region, TokenDocument,
+        TokenFindMovementPathWaypoint, Wall,
+    },
+};
+
+/// A scene and the path to find through it, so a search can be reproduced outside of Foundry
+///
+/// The documents use the same fields as their Foundry sources, the ones that aren't needed for pathfinding can be
+/// left out. Instead of listing them the fixture can point to a scene exported from Foundry with `scene`, anything else
+/// the fixture lists replaces what's in the scene, and `token` can be the ID of one of its tokens.
+pub struct Fixture {
+    pub bounds: Rectangle,
+    pub grid: Grid,
+    pub walls: Vec<Wall>,
+    pub regions: Vec<Region>,
+    pub tokens: Vec<TokenDocument>,
+    /// The polygons of the explored parts of the scene, `useExploration` limits the path to them
+    pub explored: Option<Vec<Vec<Point>>>,
+    /// The token the path is for
+    pub token: TokenDocument,
+    pub waypoints: Vec<TokenFindMovementPathWaypoint>,
+    pub options: FindMovementPathOptions,
+    pub grid_measure_path_result: GridMeasurePathResult,
+    /// The waypoints the path is expected to go through
+    pub expected: Option<Vec<ElevatedPoint>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FixtureSource {
+    /// The path of a scene export, relative to the fixture
+    scene: Option<PathBuf>,
+    bounds: Option<RectangleSource>,
+    grid: Option<GridSource>,
+    walls: Option<Vec<WallSource>>,
+    regions: Option<Vec<RegionSource>>,
+    tokens: Option<Vec<TokenSource>>,
+    /// The points of each polygon, as `[x0, y0, x1, y1, ...]`
+    explored: Option<Vec<Vec<f64>>>,
+    token: TokenReference,
+    waypoints: Vec<WaypointSource>,
+    #[serde(default)]
+    options: OptionsSource,
+    #[serde(default)]
+    grid_measure_path_result: GridMeasurePathResultSource,
+    expected: Option<Vec<ExpectedWaypointSource>>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TokenReference {
+    Id(String),
+    Document(TokenSource),
+}
+
+#[derive(Deserialize)]
+struct ExpectedWaypointSource {
+    x: f64,
+    y: f64,
+    #[serde(default)]
+    elevation: f64,
+}
+
+impl Fixture {
+    pub fn load(path: impl AsRef<Path>) -> Result<Fixture, Box<dyn Error>> {
+        let path = path.as_ref();
+        Fixture::from_json(&fs::read_to_string(path)?, path.parent().unwrap_or(Path::new("")))
+    }
+
+    /// Reads a fixture, a scene export it points to is loaded from `directory`
+    pub fn from_json(json: &str, directory: &Path) -> Result<Fixture, Box<dyn Error>> {
+        let source: FixtureSource = serde_json::from_str(json)?;
+        let scene = match &source.scene {
+            Some(path) => Some(SceneExport::load(directory.join(path))?),
+            None => None,
+        };
+
+        let (bounds, grid, walls, regions, tokens) = match scene {
+            Some(scene) => (
+                source.bounds.map_or(scene.bounds, Rectangle::from),
+                source.grid.map_or(Ok(scene.grid), Grid::try_from).map_err(unknown_grid_type)?,
+                source.walls.map_or(scene.walls, |walls| walls.into_iter().map(Wall::from).collect()),
+                source.regions.map_or(scene.regions, |regions| regions.into_iter().map(Region::from).collect()),
+                source.tokens.map_or(scene.tokens, |tokens| tokens.into_iter().map(TokenDocument::from).collect()),
+            ),
+            None => (
+                source.bounds.ok_or("missing field `bounds`")?.into(),
+                Grid::try_from(source.grid.ok_or("missing field `grid`")?).map_err(unknown_grid_type)?,
+                source.walls.unwrap_or_default().into_iter().map(Wall::from).collect(),
+                source.regions.unwrap_or_default().into_iter().map(Region::from).collect(),
+                source.tokens.unwrap_or_default().into_iter().map(TokenDocument::from).collect(),
+            ),
+        };
+
+        let token = match source.token {
+            TokenReference::Id(id) => tokens
+                .iter()
+                .find(|token| token.id == id)
+                .cloned()
+                .ok_or_else(|| format!("no token with the ID `{id}`"))?,
+            TokenReference::Document(token) => token.into(),
+        };
+
+        Ok(Fixture {
+            bounds,
+            grid,
+            walls,
+            regions,
+            tokens,
+            explored: source.explored.map(|explored| {
+                explored
+                    .into_iter()
+                    .map(|points| points.chunks_exact(2).map(|c| Point::new(c[0], c[1])).collect())
+                    .collect()
+            }),
+            token,
+            waypoints: source.waypoints.into_iter().map(TokenFindMovementPathWaypoint::from).collect(),
+            options: source.options.into(),
+            grid_measure_path_result: source.grid_measure_path_result.into(),
+            expected: source.expected.map(|expected| {
+                expected
+                    .into_iter()
+                    .map(|waypoint| ElevatedPoint::new(waypoint.x, waypoint.y, waypoint.elevation))
+                    .collect()
+            }),
+        })
+    }
+}
+
+pub(crate) fn unknown_grid_type(value: i32) -> String {
+    format!("unknown grid type {value}")
+}
+
+/// Deserializes an enum from the number Foundry stores it as
+pub(crate) fn enum_value<'de, D: Deserializer<'de>, T: TryFrom<i32, Error = i32>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    let value = i32::deserialize(deserializer)?;
+    T::try_from(value).map_err(|value| D::Error::custom(format!("unknown value {value}")))
+}
+
+/// Deserializes an enum that can be left out or `null`
+pub(crate) fn optional_enum_value<'de, D: Deserializer<'de>, T: TryFrom<i32, Error = i32>>(
+    deserializer: D,
+) -> Result<Option<T>, D::Error> {
+    match Option::<i32>::deserialize(deserializer)? {
+        Some(value) => T::try_from(value).map(Some).map_err(|value| D::Error::custom(format!("unknown value {value}"))),
+        None => Ok(None),
+    }
+}
@@ -0,0 +1,112 @@
+use serde::Deserialize;
+use wayfinder_core::types::{ElevationWindow, FindMovementPathOptions, GridMeasurePathResult, Observer, SneakOptions};
+
+/// The options passed to `findMovementPath`
+#[derive(Deserialize)]
+#[derive(Default)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsSource {
+    #[serde(default)]
+    pub use_exploration: bool,
+    pub exploration_coverage: Option<f64>,
+    #[serde(default)]
+    pub ignore_tokens: bool,
+    #[serde(default)]
+    pub closest: bool,
+    pub max_nodes: Option<u32>,
+    pub timeout: Option<f64>,
+    pub elevation_window: Option<ElevationWindowSource>,
+    pub vertical_cost: Option<f64>,
+    pub sneak: Option<SneakSource>,
+}
+
+/// The tokens the path should stay out of sight of
+#[derive(Deserialize)]
+pub struct SneakSource {
+    pub observers: Vec<ObserverSource>,
+    pub penalty: Option<f64>,
+}
+
+#[derive(Deserialize)]
+pub struct ObserverSource {
+    pub x: f64,
+    pub y: f64,
+    /// How far the observer can see in pixels, unlimited if left out
+    pub radius: Option<f64>,
+}
+
+/// Either a number of grid spaces or a range of elevations
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum ElevationWindowSource {
+    Levels(i32),
+    Range { min: Option<f64>, max: Option<f64> },
+}
+
+/// The result of measuring the path so far with `canvas.grid.measurePath`
+#[derive(Deserialize)]
+#[derive(Default)]
+pub struct GridMeasurePathResultSource {
+    #[serde(default)]
+    pub distance: f64,
+    #[serde(default)]
+    pub cost: f64,
+    #[serde(default)]
+    pub spaces: i32,
+    #[serde(default)]
+    pub diagonals: i32,
+    #[serde(default)]
+    pub euclidean: f64,
+}
+
+impl From<ElevationWindowSource> for ElevationWindow {
+    fn from(source: ElevationWindowSource) -> Self {
+        match source {
+            ElevationWindowSource::Levels(levels) => ElevationWindow::Levels(levels),
+            ElevationWindowSource::Range { min, max } => {
+                ElevationWindow::Range { min: min.unwrap_or(f64::NEG_INFINITY), max: max.unwrap_or(f64::INFINITY) }
+            }
+        }
+    }
+}
+
+impl From<OptionsSource> for FindMovementPathOptions {
+    fn from(source: OptionsSource) -> Self {
+        FindMovementPathOptions {
+            use_exploration: source.use_exploration,
+            exploration_coverage: source.exploration_coverage,
+            ignore_tokens: source.ignore_tokens,
+            closest: source.closest,
+            max_nodes: source.max_nodes,
+            timeout: source.timeout,
+            elevation_window: source.elevation_window.map(ElevationWindow::from),
+            vertical_cost: source.vertical_cost,
+            sneak: source.sneak.map(SneakOptions::from),
+        }
+    }
+}
+
+impl From<SneakSource> for SneakOptions {
+    fn from(source: SneakSource) -> Self {
+        SneakOptions {
+            observers: source
+                .observers
+                .into_iter()
+                .map(|observer| Observer::new(observer.x, observer.y, observer.radius.unwrap_or(f64::INFINITY)))
+                .collect(),
+            penalty: source.penalty,
+        }
+    }
+}
+
+impl From<GridMeasurePathResultSource> for GridMeasurePathResult {
+    fn from(source: GridMeasurePathResultSource) -> Self {
+        GridMeasurePathResult {
+            distance: source.distance,
+            cost: source.cost,
+            spaces: source.spaces,
+            diagonals: source.diagonals,
+            euclidean: source.euclidean,
+        }
+    }
+}
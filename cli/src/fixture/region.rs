@@ -0,0 +1,185 @@
+use super::enum_value;
+use serde::Deserialize;
+use std::collections::HashMap;
+use wayfinder_core::{
+    enums::RegionVisibility,
+    modules::geometry,
+    types::{Point, Region, RegionBehavior, RegionBehaviorSystem, RegionElevation, RegionFlags, RegionShape},
+};
+
+/// A region, with the same fields as its document source
+#[derive(Deserialize)]
+pub struct RegionSource {
+    #[serde(rename = "_id", default)]
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub color: String,
+    pub shapes: Vec<RegionShapeSource>,
+    #[serde(default)]
+    pub elevation: RegionElevationSource,
+    #[serde(default)]
+    pub behaviors: Vec<RegionBehaviorSource>,
+    #[serde(deserialize_with = "enum_value", default = "layer_visibility")]
+    pub visibility: RegionVisibility,
+    #[serde(default)]
+    pub locked: bool,
+    #[serde(default)]
+    pub flags: RegionFlagsSource,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum RegionShapeSource {
+    Rectangle {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        #[serde(default)]
+        rotation: f64,
+        #[serde(default)]
+        hole: bool,
+    },
+    Circle {
+        x: f64,
+        y: f64,
+        radius: f64,
+        #[serde(default)]
+        hole: bool,
+    },
+    Ellipse {
+        x: f64,
+        y: f64,
+        #[serde(rename = "radiusX")]
+        radius_x: f64,
+        #[serde(rename = "radiusY")]
+        radius_y: f64,
+        #[serde(default)]
+        rotation: f64,
+        #[serde(default)]
+        hole: bool,
+    },
+    Polygon {
+        points: Vec<f64>,
+        #[serde(default)]
+        hole: bool,
+    },
+}
+
+/// The elevation range of the region, a missing or `null` elevation is unbounded
+#[derive(Deserialize)]
+#[derive(Default)]
+pub struct RegionElevationSource {
+    #[serde(default)]
+    pub bottom: Option<f64>,
+    #[serde(default)]
+    pub top: Option<f64>,
+}
+
+#[derive(Deserialize)]
+pub struct RegionBehaviorSource {
+    #[serde(rename = "_id", default)]
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    pub r#type: String,
+    #[serde(default)]
+    pub system: RegionBehaviorSystemSource,
+    #[serde(default)]
+    pub disabled: bool,
+}
+
+#[derive(Deserialize)]
+#[derive(Default)]
+pub struct RegionBehaviorSystemSource {
+    #[serde(default)]
+    pub difficulties: HashMap<String, f64>,
+}
+
+#[derive(Deserialize)]
+#[derive(Default)]
+pub struct RegionFlagsSource {
+    #[serde(default)]
+    pub wayfinder: Option<WayfinderRegionFlagsSource>,
+}
+
+#[derive(Deserialize)]
+#[derive(Default)]
+#[serde(rename_all = "camelCase")]
+pub struct WayfinderRegionFlagsSource {
+    #[serde(default)]
+    pub impassable: Option<bool>,
+    #[serde(default)]
+    pub movement_cost: Option<f64>,
+    #[serde(default)]
+    pub water: Option<bool>,
+}
+
+fn layer_visibility() -> RegionVisibility {
+    RegionVisibility::Layer
+}
+
+impl From<RegionShapeSource> for RegionShape {
+    fn from(source: RegionShapeSource) -> Self {
+        match source {
+            RegionShapeSource::Rectangle { x, y, width, height, rotation, hole } => {
+                RegionShape::Rectangle { x, y, width, height, rotation, hole }
+            }
+            RegionShapeSource::Circle { x, y, radius, hole } => RegionShape::Circle { x, y, radius, hole },
+            RegionShapeSource::Ellipse { x, y, radius_x, radius_y, rotation, hole } => {
+                RegionShape::Ellipse { x, y, radius_x, radius_y, rotation, hole }
+            }
+            RegionShapeSource::Polygon { points, hole } => {
+                RegionShape::Polygon { points: points.chunks_exact(2).map(|c| Point::new(c[0], c[1])).collect(), hole }
+            }
+        }
+    }
+}
+
+impl From<RegionBehaviorSource> for RegionBehavior {
+    fn from(source: RegionBehaviorSource) -> Self {
+        RegionBehavior {
+            id: source.id,
+            name: source.name,
+            system: match source.r#type.as_str() {
+                "modifyMovementCost" => {
+                    RegionBehaviorSystem::ModifyMovementCost { difficulties: source.system.difficulties }
+                }
+                _ => RegionBehaviorSystem::Other,
+            },
+            disabled: source.disabled,
+        }
+    }
+}
+
+impl From<RegionSource> for Region {
+    fn from(source: RegionSource) -> Self {
+        let shapes: Vec<RegionShape> = source.shapes.into_iter().map(RegionShape::from).collect();
+        let polygons = Region::create_polygons(&shapes);
+        let bounds = geometry::polygons_bounds(&polygons);
+        let flags = source.flags.wayfinder.unwrap_or_default();
+
+        Region {
+            id: source.id,
+            name: source.name,
+            color: source.color,
+            shapes,
+            elevation: RegionElevation {
+                bottom: source.elevation.bottom.unwrap_or(f64::NEG_INFINITY),
+                top: source.elevation.top.unwrap_or(f64::INFINITY),
+            },
+            behaviors: source.behaviors.into_iter().map(RegionBehavior::from).collect(),
+            visibility: source.visibility,
+            locked: source.locked,
+            flags: RegionFlags {
+                impassable: flags.impassable.unwrap_or(false),
+                movement_cost: flags.movement_cost,
+                water: flags.water.unwrap_or(false),
+            },
+            polygons,
+            bounds,
+        }
+    }
+}
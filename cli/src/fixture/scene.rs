@@ -0,0 +1,82 @@
+use super::{unknown_grid_type, GridSource, RegionSource, TokenSource, WallSource};
+use serde::{de::Error as _, Deserialize};
+use std::{error::Error, fs, path::Path};
+use wayfinder_core::{
+    enums::Grid,
+    types::{Rectangle, Region, TokenDocument, Wall},
+};
+
+/// A scene exported from Foundry with "Export Data", the fields that aren't needed for pathfinding are ignored
+pub struct SceneExport {
+    pub name: String,
+    /// The area of the scene inside of its padding, the same as `canvas.dimensions.sceneRect`
+    pub bounds: Rectangle,
+    pub grid: Grid,
+    pub walls: Vec<Wall>,
+    pub regions: Vec<Region>,
+    pub tokens: Vec<TokenDocument>,
+}
+
+#[derive(Deserialize)]
+struct SceneSource {
+    #[serde(default)]
+    name: String,
+    width: f64,
+    height: f64,
+    #[serde(default)]
+    padding: f64,
+    grid: GridSource,
+    #[serde(default)]
+    walls: Vec<WallSource>,
+    #[serde(default)]
+    regions: Vec<RegionSource>,
+    #[serde(default)]
+    tokens: Vec<TokenSource>,
+}
+
+impl SceneExport {
+    pub fn load(path: impl AsRef<Path>) -> Result<SceneExport, Box<dyn Error>> {
+        Ok(SceneExport::from_json(&fs::read_to_string(path)?)?)
+    }
+
+    pub fn from_json(json: &str) -> serde_json::Result<SceneExport> {
+        SceneExport::try_from(serde_json::from_str::<SceneSource>(json)?).map_err(serde_json::Error::custom)
+    }
+}
+
+impl TryFrom<SceneSource> for SceneExport {
+    type Error = String;
+
+    fn try_from(source: SceneSource) -> Result<Self, Self::Error> {
+        let grid = Grid::try_from(source.grid).map_err(unknown_grid_type)?;
+
+        Ok(SceneExport {
+            name: source.name,
+            bounds: scene_rect(&grid, source.width, source.height, source.padding),
+            grid,
+            walls: source.walls.into_iter().map(Wall::from).collect(),
+            regions: source.regions.into_iter().map(Region::from).collect(),
+            tokens: source.tokens.into_iter().map(TokenDocument::from).collect(),
+        })
+    }
+}
+
+/// Offsets the scene by its padding, which Foundry rounds up to a whole number of grid spaces
+///
+/// Hexagonal grids are padded by whole rows or columns of hexagons, Foundry also aligns them to the hexagons so the
+/// offset can be off by part of a space.
+fn scene_rect(grid: &Grid, width: f64, height: f64, padding: f64) -> Rectangle {
+    let (step_x, step_y) = match grid {
+        Grid::Gridless(gridless_grid) => (gridless_grid.size as f64, gridless_grid.size as f64),
+        Grid::Square(square_grid) => (square_grid.size as f64, square_grid.size as f64),
+        Grid::Hexagonal(hexagonal_grid) if hexagonal_grid.columns => {
+            (hexagonal_grid.size_x * 0.75, hexagonal_grid.size_y)
+        }
+        Grid::Hexagonal(hexagonal_grid) => (hexagonal_grid.size_x, hexagonal_grid.size_y * 0.75),
+    };
+
+    let x = (padding * width / step_x).ceil() * step_x;
+    let y = (padding * height / step_y).ceil() * step_y;
+
+    Rectangle::new(x, y, width, height)
+}
@@ -0,0 +1,93 @@
+use super::{enum_value, optional_enum_value};
+use serde::Deserialize;
+use wayfinder_core::{
+    enums::{TokenDisposition, TokenShapeType},
+    types::{TokenDocument, TokenFindMovementPathWaypoint},
+};
+
+/// A token, with the same fields as its document source
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenSource {
+    #[serde(rename = "_id", default)]
+    pub id: String,
+    pub x: f64,
+    pub y: f64,
+    #[serde(default)]
+    pub elevation: f64,
+    #[serde(default = "one")]
+    pub width: f64,
+    #[serde(default = "one")]
+    pub height: f64,
+    #[serde(deserialize_with = "enum_value", default = "rectangle_shape")]
+    pub shape: TokenShapeType,
+    #[serde(default)]
+    pub movement_action: Option<String>,
+    #[serde(deserialize_with = "enum_value", default = "hostile_disposition")]
+    pub disposition: TokenDisposition,
+    #[serde(default)]
+    pub hidden: bool,
+}
+
+/// A waypoint to find a path through, any field that's left out is taken from the previous waypoint
+#[derive(Deserialize)]
+#[derive(Default)]
+pub struct WaypointSource {
+    pub x: Option<f64>,
+    pub y: Option<f64>,
+    pub elevation: Option<f64>,
+    pub width: Option<f64>,
+    pub height: Option<f64>,
+    #[serde(deserialize_with = "optional_enum_value", default)]
+    pub shape: Option<TokenShapeType>,
+    pub action: Option<String>,
+    pub snapped: Option<bool>,
+    pub explicit: Option<bool>,
+    pub checkpoint: Option<bool>,
+}
+
+fn one() -> f64 {
+    1.0
+}
+
+fn rectangle_shape() -> TokenShapeType {
+    TokenShapeType::Rectangle1
+}
+
+fn hostile_disposition() -> TokenDisposition {
+    TokenDisposition::Hostile
+}
+
+impl From<TokenSource> for TokenDocument {
+    fn from(source: TokenSource) -> Self {
+        TokenDocument {
+            id: source.id,
+            x: source.x,
+            y: source.y,
+            elevation: source.elevation,
+            width: source.width,
+            height: source.height,
+            shape: source.shape,
+            movement_action: source.movement_action.unwrap_or_else(|| String::from("walk")),
+            disposition: source.disposition,
+            hidden: source.hidden,
+        }
+    }
+}
+
+impl From<WaypointSource> for TokenFindMovementPathWaypoint {
+    fn from(source: WaypointSource) -> Self {
+        TokenFindMovementPathWaypoint {
+            x: source.x,
+            y: source.y,
+            elevation: source.elevation,
+            width: source.width,
+            height: source.height,
+            shape: source.shape,
+            action: source.action,
+            snapped: source.snapped,
+            explicit: source.explicit,
+            checkpoint: source.checkpoint,
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use super::enum_value;
+use serde::Deserialize;
+use wayfinder_core::{
+    enums::{WallDirection, WallDoorState, WallDoorType, WallMovementType, WallSenseType},
+    types::{Wall, WallFlags, WallHeight, WallThreshold},
+};
+
+/// A wall, with the same fields as its document source
+#[derive(Deserialize)]
+pub struct WallSource {
+    #[serde(rename = "_id", default)]
+    pub id: String,
+    pub c: [f64; 4],
+    #[serde(deserialize_with = "enum_value", default = "normal_sense")]
+    pub light: WallSenseType,
+    #[serde(deserialize_with = "enum_value", default = "normal_movement")]
+    pub r#move: WallMovementType,
+    #[serde(deserialize_with = "enum_value", default = "normal_sense")]
+    pub sight: WallSenseType,
+    #[serde(deserialize_with = "enum_value", default = "normal_sense")]
+    pub sound: WallSenseType,
+    #[serde(deserialize_with = "enum_value", default = "both_directions")]
+    pub dir: WallDirection,
+    #[serde(deserialize_with = "enum_value", default = "no_door")]
+    pub door: WallDoorType,
+    #[serde(deserialize_with = "enum_value", default = "closed_door")]
+    pub ds: WallDoorState,
+    #[serde(default)]
+    pub flags: WallFlagsSource,
+    #[serde(default)]
+    pub threshold: WallThresholdSource,
+}
+
+/// The distances, in scene units, where proximity and distance walls stop restricting each sense
+#[derive(Deserialize)]
+#[derive(Default)]
+pub struct WallThresholdSource {
+    #[serde(default)]
+    pub light: Option<f64>,
+    #[serde(default)]
+    pub sight: Option<f64>,
+    #[serde(default)]
+    pub sound: Option<f64>,
+}
+
+#[derive(Deserialize)]
+#[derive(Default)]
+pub struct WallFlagsSource {
+    #[serde(default)]
+    pub wayfinder: Option<WayfinderWallFlagsSource>,
+    #[serde(rename = "wall-height", default)]
+    pub wall_height: Option<WallHeightSource>,
+}
+
+#[derive(Deserialize)]
+pub struct WayfinderWallFlagsSource {
+    #[serde(default)]
+    pub burrow: Option<bool>,
+}
+
+/// The flags set by the Wall Height module, a missing or `null` elevation is unbounded
+#[derive(Deserialize)]
+pub struct WallHeightSource {
+    #[serde(default)]
+    pub bottom: Option<f64>,
+    #[serde(default)]
+    pub top: Option<f64>,
+}
+
+fn normal_sense() -> WallSenseType {
+    WallSenseType::Normal
+}
+
+fn normal_movement() -> WallMovementType {
+    WallMovementType::Normal
+}
+
+fn both_directions() -> WallDirection {
+    WallDirection::Both
+}
+
+fn no_door() -> WallDoorType {
+    WallDoorType::None
+}
+
+fn closed_door() -> WallDoorState {
+    WallDoorState::Closed
+}
+
+impl From<WallSource> for Wall {
+    fn from(source: WallSource) -> Self {
+        let WallFlagsSource { wayfinder, wall_height } = source.flags;
+
+        Wall {
+            id: source.id,
+            c: source.c,
+            light: source.light,
+            r#move: source.r#move,
+            sight: source.sight,
+            sound: source.sound,
+            dir: source.dir,
+            door: source.door,
+            ds: source.ds,
+            flags: WallFlags { burrow: wayfinder.and_then(|flags| flags.burrow).unwrap_or(false) },
+            height: WallHeight {
+                bottom: wall_height.as_ref().and_then(|height| height.bottom).unwrap_or(f64::NEG_INFINITY),
+                top: wall_height.as_ref().and_then(|height| height.top).unwrap_or(f64::INFINITY),
+            },
+            threshold: WallThreshold {
+                light: source.threshold.light,
+                sight: source.threshold.sight,
+                sound: source.threshold.sound,
+            },
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod fixture;
+pub mod output;
+pub mod search;
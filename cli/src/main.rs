@@ -0,0 +1,72 @@
+use std::{env, process::ExitCode};
+use wayfinder_cli::{
+    fixture::Fixture,
+    output::{diff_waypoints, ResultOutput},
+    search::find_movement_path,
+};
+
+const USAGE: &str = "usage: wayfinder [--check] <fixture.json>...";
+
+/// Finds the path through each fixture and prints the result, or with `--check` compares the waypoints against the
+/// ones the fixture expects
+fn main() -> ExitCode {
+    let mut check = false;
+    let mut paths = Vec::new();
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--check" => check = true,
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return ExitCode::SUCCESS;
+            }
+            _ => paths.push(arg),
+        }
+    }
+
+    if paths.is_empty() {
+        eprintln!("{USAGE}");
+        return ExitCode::FAILURE;
+    }
+
+    let mut failed = false;
+    for path in &paths {
+        let fixture = match Fixture::load(path) {
+            Ok(fixture) => fixture,
+            Err(error) => {
+                eprintln!("{path}: {error}");
+                failed = true;
+                continue;
+            }
+        };
+
+        let result = find_movement_path(&fixture);
+
+        if !check {
+            println!("{}", serde_json::to_string_pretty(&ResultOutput::from(&result)).unwrap());
+            continue;
+        }
+
+        match &fixture.expected {
+            None => {
+                eprintln!("{path}: no expected waypoints");
+                failed = true;
+            }
+            Some(expected) => match diff_waypoints(expected, &result) {
+                None => println!("{path}: ok"),
+                Some(lines) => {
+                    println!("{path}: waypoints differ");
+                    for line in lines {
+                        println!("{line}");
+                    }
+                    failed = true;
+                }
+            },
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
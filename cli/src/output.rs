@@ -0,0 +1,107 @@
+use serde::Serialize;
+use wayfinder_core::types::{ElevatedPoint, FindMovementPathResult, FindMovementPathSegment, TokenMovementWaypoint};
+
+/// The result of a search, in the same shape `findMovementPath` resolves to
+#[derive(Serialize)]
+pub struct ResultOutput {
+    pub waypoints: Vec<WaypointOutput>,
+    pub segments: Vec<SegmentOutput>,
+    pub cost: f64,
+    pub distance: f64,
+    pub complete: bool,
+    pub truncated: bool,
+    pub unreachable: Option<usize>,
+}
+
+#[derive(Serialize)]
+pub struct WaypointOutput {
+    pub x: f64,
+    pub y: f64,
+    pub elevation: f64,
+    pub width: f64,
+    pub height: f64,
+    pub shape: i32,
+    pub action: String,
+    pub snapped: bool,
+    pub explicit: bool,
+    pub checkpoint: bool,
+}
+
+#[derive(Serialize)]
+pub struct SegmentOutput {
+    pub cost: f64,
+    pub distance: f64,
+}
+
+impl From<&TokenMovementWaypoint> for WaypointOutput {
+    fn from(waypoint: &TokenMovementWaypoint) -> Self {
+        WaypointOutput {
+            x: waypoint.x,
+            y: waypoint.y,
+            elevation: waypoint.elevation,
+            width: waypoint.width,
+            height: waypoint.height,
+            shape: waypoint.shape as i32,
+            action: waypoint.action.clone(),
+            snapped: waypoint.snapped,
+            explicit: waypoint.explicit,
+            checkpoint: waypoint.checkpoint,
+        }
+    }
+}
+
+impl From<&FindMovementPathSegment> for SegmentOutput {
+    fn from(segment: &FindMovementPathSegment) -> Self {
+        SegmentOutput { cost: segment.cost, distance: segment.distance }
+    }
+}
+
+impl From<&FindMovementPathResult> for ResultOutput {
+    fn from(result: &FindMovementPathResult) -> Self {
+        ResultOutput {
+            waypoints: result.waypoints.iter().map(WaypointOutput::from).collect(),
+            segments: result.segments.iter().map(SegmentOutput::from).collect(),
+            cost: result.cost,
+            distance: result.distance,
+            complete: result.unreachable.is_none() && !result.truncated,
+            truncated: result.truncated,
+            unreachable: result.unreachable,
+        }
+    }
+}
+
+/// Compares the waypoints of a path against the ones it was expected to go through
+///
+/// Returns a line for each waypoint, prefixed with `-` if it was expected but missing and `+` if it wasn't expected,
+/// or `None` if the path matches.
+pub fn diff_waypoints(expected: &[ElevatedPoint], result: &FindMovementPathResult) -> Option<Vec<String>> {
+    let actual: Vec<ElevatedPoint> =
+        result.waypoints.iter().map(TokenMovementWaypoint::create_elevated_point).collect();
+
+    // Waypoints are compared to the nearest pixel, so a fixture can be written by hand
+    let same = |a: &ElevatedPoint, b: &ElevatedPoint| {
+        (a.x - b.x).abs() < 0.5 && (a.y - b.y).abs() < 0.5 && (a.elevation - b.elevation).abs() < 0.5
+    };
+
+    if expected.len() == actual.len() && expected.iter().zip(&actual).all(|(a, b)| same(a, b)) {
+        return None;
+    }
+
+    let format = |point: &ElevatedPoint| format!("({}, {}, {})", point.x, point.y, point.elevation);
+    let mut lines = Vec::new();
+    for index in 0..usize::max(expected.len(), actual.len()) {
+        match (expected.get(index), actual.get(index)) {
+            (Some(a), Some(b)) if same(a, b) => lines.push(format!("  {index}: {}", format(a))),
+            (a, b) => {
+                if let Some(a) = a {
+                    lines.push(format!("- {index}: {}", format(a)));
+                }
+                if let Some(b) = b {
+                    lines.push(format!("+ {index}: {}", format(b)));
+                }
+            }
+        }
+    }
+
+    Some(lines)
+}
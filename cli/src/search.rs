@@ -0,0 +1,56 @@
+use crate::fixture::Fixture;
+use std::time::{Duration, Instant};
+use wayfinder_core::{
+    enums::Grid,
+    scene::{Fog, SceneState},
+    traits::{AStar, Node, TokenShape},
+    types::{FindMovementPathResult, MovementPathSearch, TokenMovementWaypoint},
+};
+
+/// The resolution the explored polygons of a fixture are drawn at, one pixel for every 4x4 area of the scene
+const FOG_RESOLUTION: f64 = 0.25;
+
+/// Finds the path described by a fixture, the same way `findMovementPath` does in Foundry
+pub fn find_movement_path(fixture: &Fixture) -> FindMovementPathResult {
+    let mut waypoints = Vec::<TokenMovementWaypoint>::new();
+    let mut default_waypoint = &fixture.token.create_waypoint();
+    for waypoint in &fixture.waypoints {
+        waypoints.push(waypoint.create_waypoint(default_waypoint));
+        default_waypoint = waypoints.last().unwrap();
+    }
+
+    let result = match &fixture.grid {
+        Grid::Gridless(gridless_grid) => run_path_search(gridless_grid, fixture, waypoints),
+        Grid::Square(square_grid) => run_path_search(square_grid, fixture, waypoints),
+        Grid::Hexagonal(hexagonal_grid) => run_path_search(hexagonal_grid, fixture, waypoints),
+    };
+
+    result.scale(fixture.grid.distance())
+}
+
+fn run_path_search<N: Node + Eq, T: TokenShape, G: AStar<N, T>>(
+    grid: &G,
+    fixture: &Fixture,
+    waypoints: Vec<TokenMovementWaypoint>,
+) -> FindMovementPathResult {
+    let mut scene = SceneState::new(
+        fixture.bounds,
+        &fixture.grid,
+        fixture.walls.clone(),
+        fixture.regions.clone(),
+        fixture.tokens.clone(),
+    );
+    scene.fog = fixture.explored.as_ref().map(|polygons| Fog::from_polygons(fixture.bounds, FOG_RESOLUTION, polygons));
+
+    let search = MovementPathSearch::new(
+        grid,
+        &scene,
+        &fixture.token,
+        waypoints,
+        fixture.options.clone(),
+        &fixture.grid_measure_path_result,
+    );
+    let deadline = fixture.options.timeout.map(|timeout| Instant::now() + Duration::from_secs_f64(timeout / 1000.0));
+
+    search.run(grid, &scene, || deadline.is_some_and(|deadline| Instant::now() >= deadline))
+}
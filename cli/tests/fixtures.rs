@@ -0,0 +1,115 @@
+use std::{fs, path::Path};
+use wayfinder_cli::{
+    fixture::{Fixture, SceneExport},
+    output::diff_waypoints,
+    search::find_movement_path,
+};
+use wayfinder_core::enums::{Grid, TokenDisposition};
+
+#[test]
+fn fixtures_find_the_expected_paths() {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let mut paths: Vec<_> =
+        fs::read_dir(directory).unwrap().map(|entry| entry.unwrap().path()).filter(|path| path.is_file()).collect();
+    paths.sort();
+
+    assert!(!paths.is_empty());
+
+    for path in paths {
+        let fixture = Fixture::load(&path).unwrap_or_else(|error| panic!("{}: {error}", path.display()));
+        let expected = fixture.expected.as_ref().unwrap_or_else(|| panic!("{}: no expected waypoints", path.display()));
+        let result = find_movement_path(&fixture);
+
+        if let Some(lines) = diff_waypoints(expected, &result) {
+            panic!("{}: waypoints differ\n{}", path.display(), lines.join("\n"));
+        }
+    }
+}
+
+#[test]
+fn fixture_defaults_match_foundry() {
+    let fixture = Fixture::from_json(
+        r#"{
+            "bounds": { "x": 0, "y": 0, "width": 1000, "height": 1000 },
+            "grid": { "type": 5, "size": 100, "distance": 5 },
+            "walls": [{ "c": [0, 0, 100, 0], "flags": { "wall-height": { "bottom": 0, "top": null } } }],
+            "regions": [{ "shapes": [{ "type": "polygon", "points": [0, 0, 100, 0, 100, 100] }] }],
+            "token": { "x": 0, "y": 0 },
+            "waypoints": [{}, { "x": 100, "y": 100, "shape": 1 }],
+            "options": { "elevationWindow": { "max": 10 } }
+        }"#,
+        Path::new(""),
+    )
+    .unwrap();
+
+    let Grid::Hexagonal(grid) = fixture.grid else { panic!("expected a hexagonal grid") };
+    assert!(grid.columns && grid.even);
+    assert_eq!(grid.size_y, 100.0);
+
+    let wall = &fixture.walls[0];
+    assert!(wall.blocks_movement());
+    assert_eq!((wall.height.bottom, wall.height.top), (0.0, f64::INFINITY));
+
+    let region = &fixture.regions[0];
+    assert_eq!(region.polygons[0].len(), 3);
+    assert_eq!(region.elevation.bottom, f64::NEG_INFINITY);
+
+    assert_eq!(fixture.token.movement_action, "walk");
+    assert_eq!(fixture.waypoints[0].x, None);
+    assert!(fixture.waypoints[1].shape.is_some());
+    assert!(fixture.expected.is_none());
+}
+
+#[test]
+fn fixture_rejects_unknown_enum_values() {
+    let error = Fixture::from_json(
+        r#"{
+            "bounds": { "x": 0, "y": 0, "width": 1000, "height": 1000 },
+            "grid": { "type": 1, "size": 100, "distance": 5, "diagonals": 9 },
+            "token": { "x": 0, "y": 0 },
+            "waypoints": []
+        }"#,
+        Path::new(""),
+    )
+    .err()
+    .unwrap();
+
+    assert!(error.to_string().contains("unknown value 9"));
+}
+
+#[test]
+fn fixture_rejects_unknown_grid_types() {
+    let error = Fixture::from_json(
+        r#"{
+            "bounds": { "x": 0, "y": 0, "width": 1000, "height": 1000 },
+            "grid": { "type": 6, "size": 100, "distance": 5 },
+            "token": { "x": 0, "y": 0 },
+            "waypoints": []
+        }"#,
+        Path::new(""),
+    )
+    .err()
+    .unwrap();
+
+    assert!(error.to_string().contains("unknown grid type 6"));
+    assert!(SceneExport::from_json(
+        r#"{ "width": 1000, "height": 1000, "grid": { "type": 6, "size": 100, "distance": 5 } }"#
+    )
+    .is_err());
+}
+
+#[test]
+fn scene_exports_are_offset_by_their_padding() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/scenes/divided-room.json");
+    let scene = SceneExport::load(path).unwrap();
+
+    assert_eq!(scene.name, "Divided Room");
+    assert_eq!(
+        (scene.bounds.x, scene.bounds.y, scene.bounds.width, scene.bounds.height),
+        (300.0, 300.0, 1000.0, 1000.0)
+    );
+    assert_eq!(scene.grid.distance(), 5.0);
+    assert_eq!((scene.walls.len(), scene.regions.len(), scene.tokens.len()), (1, 1, 2));
+    assert!(scene.regions[0].flags.impassable);
+    assert_eq!(scene.tokens[1].disposition, TokenDisposition::Hostile);
+}
@@ -0,0 +1,61 @@
+//! Helpers shared by the benchmarks
+
+use std::{hint::black_box, time::Instant};
+use wayfinder_core::{
+    enums::{WallDirection, WallDoorState, WallDoorType, WallMovementType, WallSenseType},
+    types::{Wall, WallFlags, WallHeight, WallThreshold},
+};
+
+/// A small xorshift generator, so every run uses the same scene
+pub struct Random(pub u64);
+
+impl Random {
+    pub fn next(&mut self) -> f64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    pub fn range(&mut self, min: f64, max: f64) -> f64 {
+        min + (self.next() * (max - min))
+    }
+}
+
+/// A wall between 50 and 200 pixels long somewhere in a `width` by `height` scene
+pub fn wall(id: usize, width: f64, height: f64, random: &mut Random) -> Wall {
+    let x = random.range(0.0, width);
+    let y = random.range(0.0, height);
+    let angle = random.range(0.0, std::f64::consts::TAU);
+    let length = random.range(50.0, 200.0);
+
+    Wall {
+        id: id.to_string(),
+        c: [x, y, x + (length * angle.cos()), y + (length * angle.sin())],
+        light: WallSenseType::Normal,
+        r#move: WallMovementType::Normal,
+        sight: WallSenseType::Normal,
+        sound: WallSenseType::Normal,
+        dir: WallDirection::Both,
+        door: WallDoorType::None,
+        ds: WallDoorState::Closed,
+        flags: WallFlags { burrow: false },
+        height: WallHeight { bottom: f64::NEG_INFINITY, top: f64::INFINITY },
+        threshold: WallThreshold::default(),
+    }
+}
+
+/// Runs `f` once, printing how long it took in total and for each of the `iterations` it did
+pub fn time<R>(name: &str, iterations: usize, f: impl FnOnce() -> R) -> R {
+    let start = Instant::now();
+    let result = black_box(f());
+    let elapsed = start.elapsed();
+
+    println!(
+        "{name:<24} {:>10.2} ms {:>10.1} ns/iter",
+        elapsed.as_secs_f64() * 1000.0,
+        elapsed.as_nanos() as f64 / iterations as f64
+    );
+
+    result
+}
@@ -0,0 +1,107 @@
+//! Times finding the vertices of a gridless scene with 500 walls, checking them as neighbors, and a path search
+//! through the scene one step at a time
+//!
+//! Run with `cargo bench -p wayfinder-core --bench gridless`.
+
+mod common;
+
+use common::{time, wall, Random};
+use std::time::{Duration, Instant};
+use wayfinder_core::{
+    enums::{Grid, TokenDisposition, TokenShapeType},
+    grids::GridlessGrid,
+    nodes::GridlessNode,
+    scene::SceneState,
+    traits::BaseGrid,
+    types::{
+        ElevatedPoint, FindMovementPathOptions, GridMeasurePathResult, MovementPathSearch, MovementProfile, Occupancy,
+        Rectangle, SearchContext, TokenDocument, Wall,
+    },
+};
+
+const WIDTH: f64 = 5000.0;
+const HEIGHT: f64 = 5000.0;
+const WALLS: usize = 500;
+const NODES: usize = 100;
+
+fn main() {
+    let bounds = Rectangle::new(0.0, 0.0, WIDTH, HEIGHT);
+    let mut random = Random(0x2545F4914F6CDD1D);
+    let walls: Vec<Wall> = (0..WALLS).map(|id| wall(id, WIDTH, HEIGHT, &mut random)).collect();
+    let profile = MovementProfile::new("walk");
+    let grid = GridlessGrid { size: 100, distance: 5.0 };
+    let scene = SceneState::new(bounds, &Grid::Gridless(grid.clone()), walls, Vec::new(), Vec::new());
+
+    let token_shape = grid.get_token_shape(1.0, 1.0, TokenShapeType::Rectangle1);
+    let occupancy = Occupancy::default();
+    let context = SearchContext {
+        bounds: &bounds,
+        walls: &scene.walls,
+        regions: &scene.regions,
+        fog: &None,
+        exploration_coverage: None,
+        occupancy: &occupancy,
+        sneak: None,
+        vertices: &[],
+    };
+
+    let vertices = time("get_vertices", 1, || grid.get_vertices(&token_shape, &profile, &context));
+    println!("{:<24} {} vertices", "", vertices.len());
+
+    let nodes: Vec<GridlessNode> = (0..NODES)
+        .map(|_| {
+            let point = ElevatedPoint::new(random.range(0.0, WIDTH), random.range(0.0, HEIGHT), 0.0);
+            grid.get_node(point, &token_shape)
+        })
+        .collect();
+    let end_node = grid.get_node(ElevatedPoint::new(WIDTH - 100.0, HEIGHT - 100.0, 0.0), &token_shape);
+    let context = SearchContext { vertices: &vertices, ..context };
+
+    time("get_adjacent_nodes", NODES, || {
+        nodes
+            .iter()
+            .map(|node| grid.get_adjacent_nodes(node, &end_node, &token_shape, &(0..=0), &profile, &context).len())
+            .sum::<usize>()
+    });
+
+    let token = TokenDocument {
+        id: String::from("token"),
+        x: 0.0,
+        y: 0.0,
+        elevation: 0.0,
+        width: 1.0,
+        height: 1.0,
+        shape: TokenShapeType::Rectangle1,
+        movement_action: String::from("walk"),
+        disposition: TokenDisposition::Friendly,
+        hidden: false,
+    };
+    let start = token.create_waypoint();
+    let end = ElevatedPoint::new(WIDTH - 100.0, HEIGHT - 100.0, 0.0);
+    let waypoints = vec![start.clone(), start.from_elevated_point(end, true, true, false)];
+    let options = FindMovementPathOptions { closest: true, ..Default::default() };
+    let measurement = GridMeasurePathResult { distance: 0.0, cost: 0.0, spaces: 0, diagonals: 0, euclidean: 0.0 };
+
+    // The longest step is how long the search can keep the caller from checking for a timeout or cancellation
+    let mut search = MovementPathSearch::new(&grid, &scene, &token, waypoints, options, &measurement);
+    let mut steps = 0;
+    let mut longest = Duration::ZERO;
+    let result = time("find_path", 1, || loop {
+        let step = Instant::now();
+        let result = search.step(&grid, &scene, false);
+        steps += 1;
+        longest = longest.max(step.elapsed());
+
+        if let Some(result) = result {
+            break result;
+        }
+    });
+    println!(
+        "{:<24} {} waypoints, cost {}, unreachable {:?}",
+        "",
+        result.waypoints.len(),
+        result.cost,
+        result.unreachable
+    );
+    println!("{:<24} {steps} steps, longest {:.2} ms", "", longest.as_secs_f64() * 1000.0);
+}
@@ -0,0 +1,121 @@
+//! Times wall queries, visibility polygons, and a path search on a scene with 2000 walls
+//!
+//! Run with `cargo bench -p wayfinder-core --bench walls`.
+
+mod common;
+
+use common::{time, wall, Random};
+use wayfinder_core::{
+    enums::{Grid, GridDiagonals, TokenDisposition, TokenShapeType, WallRestrictionType},
+    grids::SquareGrid,
+    scene::{Regions, Walls},
+    traits::AStar,
+    types::{
+        ElevatedPoint, FindMovementPathOptions, GridMeasurePathResult, GridOffset3D, MovementProfile, Occupancy, Point,
+        Rectangle, SearchContext, TokenDocument, Wall,
+    },
+};
+
+const WIDTH: f64 = 5000.0;
+const HEIGHT: f64 = 5000.0;
+const WALLS: usize = 2000;
+const QUERIES: usize = 1_000_000;
+
+fn main() {
+    let bounds = Rectangle::new(0.0, 0.0, WIDTH, HEIGHT);
+    let mut random = Random(0x2545F4914F6CDD1D);
+    let walls: Vec<Wall> = (0..WALLS).map(|id| wall(id, WIDTH, HEIGHT, &mut random)).collect();
+    let profile = MovementProfile::new("walk");
+    let grid = SquareGrid { size: 100, distance: 5.0, diagonals: GridDiagonals::Equidistant };
+
+    let mut scene = time("build", WALLS, || Walls::new(bounds, &Grid::Square(grid.clone()), walls.clone()));
+
+    // Moves between the centers of neighboring grid spaces, like the search does
+    let moves: Vec<(ElevatedPoint, ElevatedPoint)> = (0..QUERIES)
+        .map(|_| {
+            let x = (random.range(0.0, WIDTH / 100.0).floor() * 100.0) + 50.0;
+            let y = (random.range(0.0, HEIGHT / 100.0).floor() * 100.0) + 50.0;
+            let dx = (random.range(0.0, 3.0).floor() - 1.0) * 100.0;
+            let dy = (random.range(0.0, 3.0).floor() - 1.0) * 100.0;
+
+            (ElevatedPoint::new(x, y, 0.0), ElevatedPoint::new(x + dx, y + dy, 0.0))
+        })
+        .collect();
+
+    time("check_collision", QUERIES, || {
+        moves.iter().filter(|(start, end)| scene.check_collision(*start, *end, &profile)).count()
+    });
+
+    time("check_point", QUERIES, || moves.iter().filter(|(start, _)| scene.check_point(*start, &profile)).count());
+
+    let offsets: Vec<(GridOffset3D, GridOffset3D)> = moves
+        .iter()
+        .map(|(start, end)| {
+            let get_offset =
+                |point: &ElevatedPoint| GridOffset3D { i: (point.y / 100.0) as i32, j: (point.x / 100.0) as i32, k: 0 };
+            (get_offset(start), get_offset(end))
+        })
+        .collect();
+
+    time("check_move", QUERIES, || {
+        offsets.iter().filter(|(start, end)| scene.check_move(*start, *end, &profile)).count()
+    });
+
+    let origins: Vec<Point> = moves.iter().take(100).map(|(start, _)| Point::from(*start)).collect();
+    time("visibility_polygon", origins.len(), || {
+        origins
+            .iter()
+            .map(|origin| scene.compute_visibility_polygon(*origin, WallRestrictionType::Sight, Some(1000.0)).len())
+            .sum::<usize>()
+    });
+
+    let regions = Regions::new(Vec::new());
+    let token = TokenDocument {
+        id: String::from("token"),
+        x: 0.0,
+        y: 0.0,
+        elevation: 0.0,
+        width: 1.0,
+        height: 1.0,
+        shape: TokenShapeType::Rectangle1,
+        movement_action: String::from("walk"),
+        disposition: TokenDisposition::Friendly,
+        hidden: false,
+    };
+    let start = token.create_waypoint();
+    let end = ElevatedPoint::new(WIDTH - 100.0, HEIGHT - 100.0, 0.0);
+    let waypoints = vec![start.clone(), start.from_elevated_point(end, true, true, false)];
+    let options = FindMovementPathOptions { closest: true, ..Default::default() };
+    let measurement = GridMeasurePathResult { distance: 0.0, cost: 0.0, spaces: 0, diagonals: 0, euclidean: 0.0 };
+    let context = SearchContext {
+        bounds: &bounds,
+        walls: &scene,
+        regions: &regions,
+        fog: &None,
+        exploration_coverage: None,
+        occupancy: &Occupancy::default(),
+        sneak: None,
+        vertices: &[],
+    };
+
+    let result = time("find_path", 1, || grid.find_path(waypoints, &token, &context, &options, &measurement));
+    println!(
+        "{:<24} {} waypoints, cost {}, unreachable {:?}",
+        "",
+        result.waypoints.len(),
+        result.cost,
+        result.unreachable
+    );
+
+    time("update_wall", WALLS, || {
+        for wall in &walls {
+            scene.update_wall(wall.clone());
+        }
+    });
+
+    time("delete_wall", WALLS, || {
+        for wall in &walls {
+            scene.delete_wall(&wall.id);
+        }
+    });
+}
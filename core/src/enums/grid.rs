@@ -0,0 +1,81 @@
+use crate::{
+    grids::{GridlessGrid, HexagonalGrid, SquareGrid},
+    traits::BaseGrid,
+    types::{ElevatedPoint, GridOffset3D},
+};
+
+#[derive(Clone)]
+#[derive(Debug)]
+pub enum Grid {
+    Gridless(GridlessGrid),
+    Square(SquareGrid),
+    Hexagonal(HexagonalGrid),
+}
+
+impl Grid {
+    pub fn distance(&self) -> f64 {
+        match self {
+            Grid::Gridless(gridless_grid) => gridless_grid.distance,
+            Grid::Square(square_grid) => square_grid.distance,
+            Grid::Hexagonal(hexagonal_grid) => hexagonal_grid.distance,
+        }
+    }
+
+    pub fn get_center_point(&self, offset: GridOffset3D) -> ElevatedPoint {
+        match self {
+            Grid::Gridless(gridless_grid) => gridless_grid.get_offset_center_point(offset),
+            Grid::Square(square_grid) => square_grid.get_offset_center_point(offset),
+            Grid::Hexagonal(hexagonal_grid) => hexagonal_grid.get_offset_center_point(offset),
+        }
+    }
+
+    pub fn get_top_left_point(&self, offset: GridOffset3D) -> ElevatedPoint {
+        match self {
+            Grid::Gridless(gridless_grid) => gridless_grid.get_offset_top_left_point(offset),
+            Grid::Square(square_grid) => square_grid.get_offset_top_left_point(offset),
+            Grid::Hexagonal(hexagonal_grid) => hexagonal_grid.get_offset_top_left_point(offset),
+        }
+    }
+
+    pub fn size(&self) -> f64 {
+        match self {
+            Grid::Gridless(gridless_grid) => gridless_grid.size as f64,
+            Grid::Square(square_grid) => square_grid.size as f64,
+            Grid::Hexagonal(hexagonal_grid) => hexagonal_grid.size as f64,
+        }
+    }
+
+    pub fn size_x(&self) -> f64 {
+        match self {
+            Grid::Gridless(gridless_grid) => gridless_grid.size as f64,
+            Grid::Square(square_grid) => square_grid.size as f64,
+            Grid::Hexagonal(hexagonal_grid) => hexagonal_grid.size_x,
+        }
+    }
+
+    pub fn size_y(&self) -> f64 {
+        match self {
+            Grid::Gridless(gridless_grid) => gridless_grid.size as f64,
+            Grid::Square(square_grid) => square_grid.size as f64,
+            Grid::Hexagonal(hexagonal_grid) => hexagonal_grid.size_y,
+        }
+    }
+}
+
+impl From<GridlessGrid> for Grid {
+    fn from(gridless_grid: GridlessGrid) -> Self {
+        Grid::Gridless(gridless_grid)
+    }
+}
+
+impl From<SquareGrid> for Grid {
+    fn from(square_grid: SquareGrid) -> Self {
+        Grid::Square(square_grid)
+    }
+}
+
+impl From<HexagonalGrid> for Grid {
+    fn from(hexagonal_grid: HexagonalGrid) -> Self {
+        Grid::Hexagonal(hexagonal_grid)
+    }
+}
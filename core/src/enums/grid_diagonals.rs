@@ -0,0 +1,37 @@
+#[derive(Clone, Copy)]
+#[derive(Debug)]
+#[derive(Hash)]
+#[derive(PartialEq, Eq)]
+#[derive(PartialOrd, Ord)]
+pub enum GridDiagonals {
+    Equidistant = 0,
+    Exact = 1,
+    Approximate = 2,
+    Rectilinear = 3,
+    Alternating1 = 4,
+    Alternating2 = 5,
+    Illegal = 6,
+}
+
+impl GridDiagonals {
+    pub fn is_alternating(&self) -> bool {
+        matches!(self, GridDiagonals::Alternating1 | GridDiagonals::Alternating2)
+    }
+}
+
+impl TryFrom<i32> for GridDiagonals {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(GridDiagonals::Equidistant),
+            1 => Ok(GridDiagonals::Exact),
+            2 => Ok(GridDiagonals::Approximate),
+            3 => Ok(GridDiagonals::Rectilinear),
+            4 => Ok(GridDiagonals::Alternating1),
+            5 => Ok(GridDiagonals::Alternating2),
+            6 => Ok(GridDiagonals::Illegal),
+            _ => Err(value),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+pub mod grid;
+pub mod grid_diagonals;
+pub mod region_visibility;
+pub mod token_disposition;
+pub mod token_shape_type;
+pub mod wall_direction;
+pub mod wall_door_state;
+pub mod wall_door_type;
+pub mod wall_movement_type;
+pub mod wall_restriction_type;
+pub mod wall_sense_type;
+
+pub use self::grid::Grid;
+pub use self::grid_diagonals::GridDiagonals;
+pub use self::region_visibility::RegionVisibility;
+pub use self::token_disposition::TokenDisposition;
+pub use self::token_shape_type::TokenShapeType;
+pub use self::wall_direction::WallDirection;
+pub use self::wall_door_state::WallDoorState;
+pub use self::wall_door_type::WallDoorType;
+pub use self::wall_movement_type::WallMovementType;
+pub use self::wall_restriction_type::WallRestrictionType;
+pub use self::wall_sense_type::WallSenseType;
@@ -0,0 +1,21 @@
+#[derive(Clone, Copy)]
+#[derive(Debug)]
+#[derive(PartialEq, Eq)]
+pub enum RegionVisibility {
+    Layer = 0,
+    Gamemaster = 1,
+    Always = 2,
+}
+
+impl TryFrom<i32> for RegionVisibility {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(RegionVisibility::Layer),
+            1 => Ok(RegionVisibility::Gamemaster),
+            2 => Ok(RegionVisibility::Always),
+            _ => Err(value),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+#[derive(Clone, Copy)]
+#[derive(Debug)]
+#[derive(PartialEq, Eq)]
+pub enum TokenDisposition {
+    Secret = -2,
+    Hostile = -1,
+    Neutral = 0,
+    Friendly = 1,
+}
+
+impl TokenDisposition {
+    /// Only friendly and hostile tokens are hostile to each other, neutral and secret tokens aren't hostile to anyone
+    pub fn is_hostile_to(&self, other: &TokenDisposition) -> bool {
+        matches!(
+            (self, other),
+            (TokenDisposition::Friendly, TokenDisposition::Hostile)
+                | (TokenDisposition::Hostile, TokenDisposition::Friendly)
+        )
+    }
+}
+
+impl TryFrom<i32> for TokenDisposition {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            -2 => Ok(TokenDisposition::Secret),
+            -1 => Ok(TokenDisposition::Hostile),
+            0 => Ok(TokenDisposition::Neutral),
+            1 => Ok(TokenDisposition::Friendly),
+            _ => Err(value),
+        }
+    }
+}
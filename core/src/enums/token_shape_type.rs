@@ -0,0 +1,28 @@
+#[derive(Clone, Copy)]
+#[derive(Debug)]
+#[derive(PartialEq, Eq)]
+#[derive(PartialOrd, Ord)]
+pub enum TokenShapeType {
+    Ellipse1 = 0,
+    Ellipse2 = 1,
+    Trapezoid1 = 2,
+    Trapezoid2 = 3,
+    Rectangle1 = 4,
+    Rectangle2 = 5,
+}
+
+impl TryFrom<i32> for TokenShapeType {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(TokenShapeType::Ellipse1),
+            1 => Ok(TokenShapeType::Ellipse2),
+            2 => Ok(TokenShapeType::Trapezoid1),
+            3 => Ok(TokenShapeType::Trapezoid2),
+            4 => Ok(TokenShapeType::Rectangle1),
+            5 => Ok(TokenShapeType::Rectangle2),
+            _ => Err(value),
+        }
+    }
+}
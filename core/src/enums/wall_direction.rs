@@ -0,0 +1,21 @@
+#[derive(Clone, Copy)]
+#[derive(Debug)]
+#[derive(PartialEq, Eq)]
+pub enum WallDirection {
+    Both = 0,
+    Left = 1,
+    Right = 2,
+}
+
+impl TryFrom<i32> for WallDirection {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(WallDirection::Both),
+            1 => Ok(WallDirection::Left),
+            2 => Ok(WallDirection::Right),
+            _ => Err(value),
+        }
+    }
+}
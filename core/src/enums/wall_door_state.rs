@@ -0,0 +1,21 @@
+#[derive(Clone, Copy)]
+#[derive(Debug)]
+#[derive(PartialEq, Eq)]
+pub enum WallDoorState {
+    Closed = 0,
+    Open = 1,
+    Locked = 2,
+}
+
+impl TryFrom<i32> for WallDoorState {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(WallDoorState::Closed),
+            1 => Ok(WallDoorState::Open),
+            2 => Ok(WallDoorState::Locked),
+            _ => Err(value),
+        }
+    }
+}
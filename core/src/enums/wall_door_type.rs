@@ -0,0 +1,21 @@
+#[derive(Clone, Copy)]
+#[derive(Debug)]
+#[derive(PartialEq, Eq)]
+pub enum WallDoorType {
+    None = 0,
+    Door = 1,
+    Secret = 2,
+}
+
+impl TryFrom<i32> for WallDoorType {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(WallDoorType::None),
+            1 => Ok(WallDoorType::Door),
+            2 => Ok(WallDoorType::Secret),
+            _ => Err(value),
+        }
+    }
+}
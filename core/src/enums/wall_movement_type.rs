@@ -0,0 +1,19 @@
+#[derive(Clone, Copy)]
+#[derive(Debug)]
+#[derive(PartialEq, Eq)]
+pub enum WallMovementType {
+    None = 0,
+    Normal = 20,
+}
+
+impl TryFrom<i32> for WallMovementType {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(WallMovementType::None),
+            20 => Ok(WallMovementType::Normal),
+            _ => Err(value),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+/// The ways a wall can restrict what passes through it, the same as `CONST.WALL_RESTRICTION_TYPES`
+#[derive(Clone, Copy)]
+#[derive(Debug)]
+#[derive(PartialEq, Eq)]
+pub enum WallRestrictionType {
+    Light,
+    Sight,
+    Sound,
+    Move,
+}
+
+impl<'a> TryFrom<&'a str> for WallRestrictionType {
+    type Error = &'a str;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        match value {
+            "light" => Ok(WallRestrictionType::Light),
+            "sight" => Ok(WallRestrictionType::Sight),
+            "sound" => Ok(WallRestrictionType::Sound),
+            "move" => Ok(WallRestrictionType::Move),
+            _ => Err(value),
+        }
+    }
+}
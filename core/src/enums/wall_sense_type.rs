@@ -0,0 +1,25 @@
+#[derive(Clone, Copy)]
+#[derive(Debug)]
+#[derive(PartialEq, Eq)]
+pub enum WallSenseType {
+    None = 0,
+    Limited = 10,
+    Normal = 20,
+    Proximity = 30,
+    Distance = 40,
+}
+
+impl TryFrom<i32> for WallSenseType {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(WallSenseType::None),
+            10 => Ok(WallSenseType::Limited),
+            20 => Ok(WallSenseType::Normal),
+            30 => Ok(WallSenseType::Proximity),
+            40 => Ok(WallSenseType::Distance),
+            _ => Err(value),
+        }
+    }
+}
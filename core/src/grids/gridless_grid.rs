@@ -0,0 +1,248 @@
+use crate::{
+    enums::TokenShapeType,
+    nodes::GridlessNode,
+    scene::Tokens,
+    traits::{node::COST_SCALE, AStar, BaseGrid, Node},
+    types::{
+        ElevatedPoint, GridOffset3D, MovementProfile, Occupancy, Point, Rectangle, SearchContext, TokenDocument,
+        TokenSquareShapeData,
+    },
+};
+use std::{collections::HashSet, ops::RangeInclusive};
+
+#[derive(Clone)]
+#[derive(Debug)]
+pub struct GridlessGrid {
+    pub size: i32,
+    pub distance: f64,
+}
+
+impl GridlessGrid {
+    /// Tokens are treated as circles wide enough to cover their longest side
+    fn get_radius(&self, token_shape: &TokenSquareShapeData) -> f64 {
+        f64::max(token_shape.width, token_shape.height) * self.size as f64 / 2.0
+    }
+}
+
+impl BaseGrid<GridlessNode, TokenSquareShapeData> for GridlessGrid {
+    fn convert_node_to_offset(&self, GridlessNode { i, j, k }: GridlessNode) -> GridOffset3D {
+        GridOffset3D { i, j, k }
+    }
+
+    fn convert_offset_to_node(&self, GridOffset3D { i, j, k }: GridOffset3D) -> GridlessNode {
+        GridlessNode { i, j, k }
+    }
+
+    fn get_adjacent_nodes(
+        &self,
+        node: &GridlessNode,
+        end_node: &GridlessNode,
+        token_shape: &TokenSquareShapeData,
+        elevation_range: &RangeInclusive<i32>,
+        profile: &MovementProfile,
+        SearchContext { bounds, walls, regions, fog, exploration_coverage, occupancy, sneak, vertices }: &SearchContext,
+    ) -> Vec<(GridlessNode, u32)> {
+        let radius = self.get_radius(token_shape);
+        let center = token_shape.center * self.size as f64;
+        let start_point = self.get_token_center_point(self.get_node_top_left_point(node), token_shape);
+        let start: Point = start_point.into();
+
+        let mut candidates: HashSet<GridlessNode> = vertices
+            .iter()
+            .map(|vertex| {
+                GridlessNode::new((vertex.y - center.y).floor() as i32, (vertex.x - center.x).floor() as i32, node.k)
+            })
+            .collect();
+        candidates.insert(*end_node);
+        candidates.remove(node);
+
+        candidates
+            .into_iter()
+            .filter(|neighbor| elevation_range.contains(&neighbor.get_elevation()))
+            .filter_map(|neighbor| {
+                let end_point = self.get_token_center_point(self.get_node_top_left_point(&neighbor), token_shape);
+                let end: Point = end_point.into();
+
+                if !bounds.contains_point(end) {
+                    return None;
+                }
+
+                if let Some(fog) = fog {
+                    let explored = match exploration_coverage {
+                        // Gridless tokens don't occupy grid spaces, so the whole token is checked at once
+                        Some(coverage) => {
+                            let width = token_shape.width * self.size as f64;
+                            let height = token_shape.height * self.size as f64;
+                            let top_left = self.get_node_top_left_point(&neighbor);
+                            let explored =
+                                fog.get_explored_coverage(&Rectangle::new(top_left.x, top_left.y, width, height));
+                            explored > 0.0 && explored >= *coverage
+                        }
+                        None => fog.is_point_explored(end),
+                    };
+                    if !explored {
+                        return None;
+                    }
+                }
+
+                if walls.check_swept_collision(start_point, end_point, radius, profile) {
+                    return None;
+                }
+
+                if occupancy.blocks_segment(start, end, neighbor.k, radius) {
+                    return None;
+                }
+
+                let mut multiplier = regions.get_segment_cost_multiplier(start_point, end_point, profile);
+                if neighbor.k != node.k {
+                    multiplier *= profile.vertical_cost;
+                }
+                if let Some(sneak) = sneak {
+                    multiplier *= sneak.get_cost_multiplier([end], walls);
+                }
+                if multiplier.is_infinite() {
+                    return None;
+                }
+
+                let distance = f64::hypot(f64::hypot(end.x - start.x, end.y - start.y), (neighbor.k - node.k) as f64);
+                let cost = distance / self.size as f64 * COST_SCALE as f64 * multiplier;
+                Some((neighbor, u32::max(cost.round() as u32, 1)))
+            })
+            .collect()
+    }
+
+    /// The corners of every wall, impassable region, and hostile token, pushed out by the radius of the token, leaving
+    /// out the ones it can't fit at
+    fn get_vertices(
+        &self,
+        token_shape: &TokenSquareShapeData,
+        profile: &MovementProfile,
+        SearchContext { walls, regions, occupancy, .. }: &SearchContext,
+    ) -> Vec<Point> {
+        let radius = self.get_radius(token_shape);
+
+        walls
+            .get_corners(radius + 1.0, profile)
+            .into_iter()
+            .chain(regions.get_corners(radius + 1.0, profile))
+            .chain(occupancy.get_corners(radius + 1.0))
+            .filter(|vertex| !walls.check_clearance(*vertex, radius, profile))
+            .collect()
+    }
+
+    fn get_heuristic(&self, node: &GridlessNode, end_node: &GridlessNode) -> u32 {
+        (node.get_distance(end_node) as f64 / self.size as f64 * COST_SCALE as f64).floor() as u32
+    }
+
+    fn get_occupancy(&self, token: &TokenDocument, tokens: &Tokens) -> Occupancy {
+        let mut occupancy = Occupancy::default();
+
+        for other in tokens.get_obstacles(token) {
+            let token_shape = self.get_token_shape(other.width, other.height, other.shape);
+            let offset = self.get_offset(other.create_waypoint().create_elevated_point(), &token_shape);
+            let bounds = Rectangle::new(
+                other.x,
+                other.y,
+                token_shape.width * self.size as f64,
+                token_shape.height * self.size as f64,
+            );
+
+            // Tokens that aren't hostile can be moved through, but a path can't end on them
+            if other.disposition.is_hostile_to(&token.disposition) {
+                occupancy.hostile_bounds.push((bounds, offset.k));
+            } else {
+                occupancy.allied_bounds.push((bounds, offset.k));
+            }
+        }
+
+        occupancy
+    }
+
+    fn is_occupied(&self, node: &GridlessNode, token_shape: &TokenSquareShapeData, occupancy: &Occupancy) -> bool {
+        let ElevatedPoint { x, y, elevation: _ } = self.get_node_top_left_point(node);
+        let bounds = Rectangle::new(x, y, token_shape.width * self.size as f64, token_shape.height * self.size as f64);
+
+        occupancy.overlaps_bounds(&bounds, node.k)
+    }
+
+    fn get_node(
+        &self,
+        ElevatedPoint { x, y, elevation }: ElevatedPoint,
+        _token_shape: &TokenSquareShapeData,
+    ) -> GridlessNode {
+        GridlessNode {
+            i: y.floor() as i32,
+            j: x.floor() as i32,
+            k: ((elevation / self.distance * self.size as f64) + 1e-8).floor() as i32,
+        }
+    }
+
+    fn get_node_center_point(&self, GridlessNode { i, j, k }: &GridlessNode) -> ElevatedPoint {
+        ElevatedPoint { x: *j as f64, y: *i as f64, elevation: (*k as f64 / self.size as f64) * self.distance }
+    }
+
+    fn get_node_top_left_point(&self, GridlessNode { i, j, k }: &GridlessNode) -> ElevatedPoint {
+        ElevatedPoint { x: *j as f64, y: *i as f64, elevation: (*k as f64 / self.size as f64) * self.distance }
+    }
+
+    fn get_occupied_grid_space_offsets(
+        &self,
+        _offset: GridOffset3D,
+        _token_shape: &TokenSquareShapeData,
+    ) -> Vec<GridOffset3D> {
+        Vec::new()
+    }
+
+    fn get_offset(
+        &self,
+        ElevatedPoint { x, y, elevation }: ElevatedPoint,
+        _token_shape: &TokenSquareShapeData,
+    ) -> GridOffset3D {
+        GridOffset3D {
+            i: y.floor() as i32,
+            j: x.floor() as i32,
+            k: ((elevation / self.distance * self.size as f64) + 1e-8).floor() as i32,
+        }
+    }
+
+    fn get_offset_center_point(&self, GridOffset3D { i, j, k }: GridOffset3D) -> ElevatedPoint {
+        ElevatedPoint { x: j as f64, y: i as f64, elevation: (k as f64 / self.size as f64) * self.distance }
+    }
+
+    fn get_offset_top_left_point(&self, GridOffset3D { i, j, k }: GridOffset3D) -> ElevatedPoint {
+        ElevatedPoint { x: j as f64, y: i as f64, elevation: (k as f64 / self.size as f64) * self.distance }
+    }
+
+    fn get_token_center_point(
+        &self,
+        ElevatedPoint { x, y, elevation }: ElevatedPoint,
+        TokenSquareShapeData { offsets: _, points: _, center, anchor: _, width: _, height: _ }: &TokenSquareShapeData,
+    ) -> ElevatedPoint {
+        ElevatedPoint { x: x + (center.x * self.size as f64), y: y + (center.y * self.size as f64), elevation }
+    }
+
+    fn get_token_shape(&self, width: f64, height: f64, _shape: TokenShapeType) -> TokenSquareShapeData {
+        let width = (width * 2.0).round() / 2.0;
+        let height = (height * 2.0).round() / 2.0;
+
+        TokenSquareShapeData {
+            offsets: vec![],
+            points: vec![
+                Point { x: 0.0, y: 0.0 },
+                Point { x: width, y: 0.0 },
+                Point { x: width, y: height },
+                Point { x: 0.0, y: height },
+            ],
+            center: Point { x: width / 2.0, y: height / 2.0 },
+            anchor: Point { x: 0.0, y: 0.0 },
+            width,
+            height,
+        }
+    }
+
+    fn simplify_path(&self, path: Vec<GridlessNode>) -> Vec<GridlessNode> {
+        path
+    }
+}
+
+impl AStar<GridlessNode, TokenSquareShapeData> for GridlessGrid {}
@@ -1,16 +1,15 @@
 use crate::{
     enums::TokenShapeType,
-    exports::{Fog, Walls},
     modules::geometry,
     nodes::HexagonalNode,
-    traits::{AStar, BaseGrid, Node, SkipLast},
+    traits::{AStar, BaseGrid},
     types::{
-        ElevatedPoint, GridMeasurePathResult, GridOffset2D, GridOffset3D, HexagonalGridCube2D, HexagonalGridCube3D,
-        Point, Rectangle, TokenDocument, TokenHexagonalShapeData, TokenMovementWaypoint,
+        ElevatedPoint, GridOffset2D, GridOffset3D, HexagonalGridCube2D, HexagonalGridCube3D, Point,
+        TokenHexagonalShapeData,
     },
 };
-use std::ops::RangeInclusive;
 
+#[derive(Clone)]
 #[derive(Debug)]
 pub struct HexagonalGrid {
     pub size: i32,
@@ -88,12 +87,12 @@ impl HexagonalGrid {
             q = j - ((i + ((if self.even { 1 } else { -1 }) * (i & 1))) >> 1);
             r = i;
         }
-        HexagonalGridCube3D { q: q, r: r, s: 0 - q - r, k }
+        HexagonalGridCube3D { q, r, s: 0 - q - r, k }
     }
 
     pub fn cube_to_offset(&self, HexagonalGridCube3D { q, r, s: _, k }: HexagonalGridCube3D) -> GridOffset3D {
         if self.columns {
-            GridOffset3D { i: q, j: r + ((q + ((if self.even { 1 } else { -1 }) * (q & 1))) >> 1), k }
+            GridOffset3D { i: r + ((q + ((if self.even { 1 } else { -1 }) * (q & 1))) >> 1), j: q, k }
         } else {
             GridOffset3D { i: r, j: q + ((r + ((if self.even { 1 } else { -1 }) * (r & 1))) >> 1), k }
         }
@@ -164,8 +163,8 @@ impl HexagonalGrid {
                 ],
                 center: Point { x: 0.25, y: 0.25 },
                 anchor: Point { x: 0.25, y: 0.25 },
-                width: width,
-                height: height,
+                width,
+                height,
             });
         } else if width == 1.0 && height == 1.0 {
             return Some(TokenHexagonalShapeData {
@@ -181,8 +180,8 @@ impl HexagonalGrid {
                 ],
                 center: Point { x: 0.5, y: 0.5 },
                 anchor: Point { x: 0.5, y: 0.5 },
-                width: width,
-                height: height,
+                width,
+                height,
             });
         } else if shape <= TokenShapeType::Trapezoid2 {
             return HexagonalGrid::create_hexagonal_ellipse_or_trapezoid(width, height, shape);
@@ -190,7 +189,7 @@ impl HexagonalGrid {
             return HexagonalGrid::create_hexagonal_rectangle(width, height, shape);
         }
 
-        return None;
+        None
     }
 
     fn create_hexagonal_ellipse_or_trapezoid(
@@ -319,7 +318,7 @@ impl HexagonalGrid {
         }
 
         let center = geometry::polygon_centroid(&points);
-        return Some(TokenHexagonalShapeData {
+        Some(TokenHexagonalShapeData {
             even_offsets,
             odd_offsets,
             points,
@@ -327,7 +326,7 @@ impl HexagonalGrid {
             anchor: if bottom % 2 != 0 { Point { x: 0.0, y: 0.5 } } else { Point { x: 0.5, y: 0.5 } },
             width,
             height,
-        });
+        })
     }
 
     fn create_hexagonal_rectangle(width: f64, height: f64, shape: TokenShapeType) -> Option<TokenHexagonalShapeData> {
@@ -439,7 +438,7 @@ impl HexagonalGrid {
             points.push(Point { x, y });
         }
 
-        return Some(TokenHexagonalShapeData {
+        Some(TokenHexagonalShapeData {
             even_offsets,
             odd_offsets,
             points,
@@ -447,7 +446,7 @@ impl HexagonalGrid {
             anchor: if even { Point { x: 0.5, y: 0.5 } } else { Point { x: 0.0, y: 0.5 } },
             width,
             height,
-        });
+        })
     }
 }
 
@@ -665,3 +664,137 @@ impl BaseGrid<HexagonalNode, TokenHexagonalShapeData> for HexagonalGrid {
 }
 
 impl AStar<HexagonalNode, TokenHexagonalShapeData> for HexagonalGrid {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHAPES: [TokenShapeType; 6] = [
+        TokenShapeType::Ellipse1,
+        TokenShapeType::Ellipse2,
+        TokenShapeType::Trapezoid1,
+        TokenShapeType::Trapezoid2,
+        TokenShapeType::Rectangle1,
+        TokenShapeType::Rectangle2,
+    ];
+
+    fn grid(columns: bool) -> HexagonalGrid {
+        HexagonalGrid {
+            size: 100,
+            distance: 5.0,
+            size_x: if columns { 200.0 / HexagonalGrid::SQRT3 } else { 100.0 },
+            size_y: if columns { 100.0 } else { 200.0 / HexagonalGrid::SQRT3 },
+            columns,
+            even: false,
+        }
+    }
+
+    #[test]
+    fn single_hex_for_every_shape() {
+        for shape in SHAPES {
+            let data = HexagonalGrid::get_hexagonal_shape(1.0, 1.0, shape, false).unwrap();
+
+            assert_eq!(data.even_offsets, vec![GridOffset2D { i: 0, j: 0 }]);
+            assert_eq!(data.odd_offsets, vec![GridOffset2D { i: 0, j: 0 }]);
+            assert_eq!(data.points.len(), 6);
+            assert_eq!((data.center.x, data.center.y), (0.5, 0.5));
+        }
+    }
+
+    #[test]
+    fn ellipses_cover_the_expected_number_of_hexes() {
+        for (size, count) in [(2.0, 3), (3.0, 7), (4.0, 12)] {
+            for shape in [TokenShapeType::Ellipse1, TokenShapeType::Ellipse2] {
+                let data = HexagonalGrid::get_hexagonal_shape(size, size, shape, false).unwrap();
+
+                assert_eq!(data.even_offsets.len(), count, "{shape:?} {size}");
+                assert_eq!(data.odd_offsets.len(), count, "{shape:?} {size}");
+            }
+        }
+    }
+
+    #[test]
+    fn outline_encloses_the_occupied_hexes() {
+        // A hex is 1 wide and 1 tall, with an area of 3/4 of that
+        for shape in SHAPES {
+            for (width, height) in [(2.0, 1.0), (2.0, 2.0), (3.0, 2.0), (3.0, 3.0), (4.0, 3.0), (2.5, 2.0)] {
+                let Some(data) = HexagonalGrid::get_hexagonal_shape(width, height, shape, false) else {
+                    continue;
+                };
+
+                let area = geometry::polygon_signed_area(&data.points).abs();
+                assert_eq!(area, 0.75 * data.even_offsets.len() as f64, "{shape:?} {width}x{height}");
+                assert_eq!(data.even_offsets.len(), data.odd_offsets.len(), "{shape:?} {width}x{height}");
+            }
+        }
+    }
+
+    #[test]
+    fn columns_transpose_rows() {
+        for shape in SHAPES {
+            let rows = HexagonalGrid::get_hexagonal_shape(3.0, 2.0, shape, false);
+            let columns = HexagonalGrid::get_hexagonal_shape(2.0, 3.0, shape, true);
+            let (Some(rows), Some(columns)) = (rows, columns) else {
+                continue;
+            };
+
+            let mut transposed: Vec<GridOffset2D> =
+                rows.even_offsets.iter().map(|GridOffset2D { i, j }| GridOffset2D { i: *j, j: *i }).collect();
+            transposed.sort();
+
+            assert_eq!(columns.even_offsets, transposed, "{shape:?}");
+            assert_eq!((columns.center.x, columns.center.y), (rows.center.y, rows.center.x), "{shape:?}");
+            assert_eq!(columns.points.len(), rows.points.len(), "{shape:?}");
+        }
+    }
+
+    #[test]
+    fn unsupported_sizes_have_no_shape() {
+        assert!(HexagonalGrid::get_hexagonal_shape(1.3, 1.0, TokenShapeType::Ellipse1, false).is_none());
+        assert!(HexagonalGrid::get_hexagonal_shape(1.0, 2.0, TokenShapeType::Ellipse1, false).is_none());
+        assert!(HexagonalGrid::get_hexagonal_shape(2.0, 3.0, TokenShapeType::Trapezoid1, false).is_none());
+        assert!(HexagonalGrid::get_hexagonal_shape(2.5, 2.0, TokenShapeType::Trapezoid1, false).is_none());
+    }
+
+    #[test]
+    fn token_shape_falls_back_to_a_rectangle() {
+        let grid = grid(false);
+        let data = grid.get_token_shape(1.5, 1.5, TokenShapeType::Ellipse1);
+        let rectangle = HexagonalGrid::get_hexagonal_shape(2.0, 2.0, TokenShapeType::Rectangle1, false).unwrap();
+
+        assert_eq!(data.even_offsets, rectangle.even_offsets);
+        assert_eq!((data.anchor.x, data.anchor.y), (rectangle.anchor.x - 0.25, rectangle.anchor.y - 0.25));
+    }
+
+    #[test]
+    fn offsets_and_cubes_round_trip() {
+        for columns in [false, true] {
+            let grid = grid(columns);
+
+            for i in -3..=3 {
+                for j in -3..=3 {
+                    let offset = GridOffset3D { i, j, k: 0 };
+                    let cube = grid.offset_to_cube(offset);
+
+                    assert_eq!(cube.q + cube.r + cube.s, 0);
+                    assert_eq!(grid.cube_to_offset(cube), offset);
+                    assert_eq!(grid.point_to_cube(grid.cube_to_point(cube)), cube);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn occupied_spaces_follow_the_row_parity() {
+        let grid = grid(false);
+        let data = HexagonalGrid::get_hexagonal_shape(2.0, 2.0, TokenShapeType::Ellipse1, false).unwrap();
+
+        let odd = grid.get_occupied_grid_space_offsets(GridOffset3D { i: 1, j: 1, k: 0 }, &data);
+        let even = grid.get_occupied_grid_space_offsets(GridOffset3D { i: 2, j: 1, k: 0 }, &data);
+
+        assert_eq!(odd.len(), 3);
+        assert_eq!(even.len(), 3);
+        assert!(odd.contains(&GridOffset3D { i: 1, j: 1, k: 0 }));
+        assert!(even.contains(&GridOffset3D { i: 2, j: 1, k: 0 }));
+    }
+}
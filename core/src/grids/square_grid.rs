@@ -1,28 +1,26 @@
 use crate::{
-    enums::TokenShapeType,
-    exports::{Fog, Walls},
+    enums::{GridDiagonals, TokenShapeType},
     nodes::SquareNode,
-    traits::{node, AStar, BaseGrid, Node, SkipLast},
-    types::{
-        ElevatedPoint, GridMeasurePathResult, GridOffset2D, GridOffset3D, Point, Rectangle, TokenDocument,
-        TokenMovementWaypoint, TokenSquareShapeData,
-    },
+    traits::{AStar, BaseGrid},
+    types::{ElevatedPoint, GridOffset2D, GridOffset3D, Point, TokenSquareShapeData},
 };
-use std::{collections::HashMap, ops::RangeInclusive, vec};
+use std::vec;
 
+#[derive(Clone)]
 #[derive(Debug)]
 pub struct SquareGrid {
     pub size: i32,
     pub distance: f64,
+    pub diagonals: GridDiagonals,
 }
 
 impl BaseGrid<SquareNode, TokenSquareShapeData> for SquareGrid {
-    fn convert_node_to_offset(&self, SquareNode { i, j, k, d: _ }: SquareNode) -> GridOffset3D {
+    fn convert_node_to_offset(&self, SquareNode { i, j, k, d: _, diagonals: _ }: SquareNode) -> GridOffset3D {
         GridOffset3D { i, j, k }
     }
 
     fn convert_offset_to_node(&self, GridOffset3D { i, j, k }: GridOffset3D) -> SquareNode {
-        SquareNode { i, j, k, d: false }
+        SquareNode { i, j, k, d: false, diagonals: self.diagonals }
     }
 
     fn get_node(
@@ -38,10 +36,11 @@ impl BaseGrid<SquareNode, TokenSquareShapeData> for SquareGrid {
             j: (x / self.size as f64).floor() as i32,
             k: ((elevation / self.distance) + 1e-8).floor() as i32,
             d: false,
+            diagonals: self.diagonals,
         }
     }
 
-    fn get_node_center_point(&self, SquareNode { i, j, k, d: _ }: &SquareNode) -> ElevatedPoint {
+    fn get_node_center_point(&self, SquareNode { i, j, k, d: _, diagonals: _ }: &SquareNode) -> ElevatedPoint {
         ElevatedPoint {
             x: (*j as f64 + 0.5) * self.size as f64,
             y: (*i as f64 + 0.5) * self.size as f64,
@@ -49,7 +48,7 @@ impl BaseGrid<SquareNode, TokenSquareShapeData> for SquareGrid {
         }
     }
 
-    fn get_node_top_left_point(&self, SquareNode { i, j, k, d: _ }: &SquareNode) -> ElevatedPoint {
+    fn get_node_top_left_point(&self, SquareNode { i, j, k, d: _, diagonals: _ }: &SquareNode) -> ElevatedPoint {
         ElevatedPoint {
             x: *j as f64 * self.size as f64,
             y: *i as f64 * self.size as f64,
@@ -0,0 +1,7 @@
+pub mod enums;
+pub mod grids;
+pub mod modules;
+pub mod nodes;
+pub mod scene;
+pub mod traits;
+pub mod types;
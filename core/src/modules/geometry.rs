@@ -0,0 +1,346 @@
+use crate::{
+    modules::{math, number},
+    types::{LineIntersection, Point, Rectangle},
+};
+
+pub fn orient_2d_fast(a: &Point, b: &Point, c: &Point) -> f64 {
+    (a.y - c.y) * (b.x - c.x) - (a.x - c.x) * (b.y - c.y)
+}
+
+pub fn line_segement_intersects(a: &Point, b: &Point, c: &Point, d: &Point) -> bool {
+    let xa = orient_2d_fast(a, b, c);
+    let xb = orient_2d_fast(a, b, d);
+
+    if xa != 0.0 && xb != 0.0 {
+        return false;
+    }
+    let xab = (xa * xb) <= 0.0;
+
+    let xcd = orient_2d_fast(c, d, a) * orient_2d_fast(c, d, b) <= 0.0;
+    xab && xcd
+}
+
+pub fn line_line_intersection(a: &Point, b: &Point, c: &Point, d: &Point) -> Option<LineIntersection> {
+    if ((a.x == b.x) && (a.y == b.y)) || ((c.x == d.x) && (c.y == d.y)) {
+        return None;
+    }
+
+    let dnm = (d.y - c.y) * (b.x - a.x) - (d.x - c.x) * (b.y - a.y);
+    if dnm == 0.0 {
+        return None;
+    }
+
+    let t0 = ((d.x - c.x) * (a.y - c.y) - (d.y - c.y) * (a.x - c.x)) / dnm;
+    let t1 = ((b.x - a.x) * (a.y - c.y) - (b.y - a.y) * (a.x - c.x)) / dnm;
+
+    Some(LineIntersection { x: a.x + t0 * (b.x - a.x), y: a.y + t0 * (b.y - a.y), t0, t1 })
+}
+
+pub fn line_segment_intersection(
+    a: &Point,
+    b: &Point,
+    c: &Point,
+    d: &Point,
+    epsilon: Option<f64>,
+) -> Option<LineIntersection> {
+    let epsilon = epsilon.unwrap_or(1e-8);
+
+    if ((a.x == b.x) && (a.y == b.y)) || ((c.x == d.x) && (c.y == d.y)) {
+        return None;
+    }
+
+    let dnm = (d.y - c.y) * (b.x - a.x) - (d.x - c.x) * (b.y - a.y);
+    if dnm == 0.0 {
+        return None;
+    }
+
+    let t0 = ((d.x - c.x) * (a.y - c.y) - (d.y - c.y) * (a.x - c.x)) / dnm;
+    if !number::between(t0, 0.0 - epsilon, 1.0 + epsilon, None) {
+        return None;
+    }
+
+    let t1 = ((b.x - a.x) * (a.y - c.y) - (b.y - a.y) * (a.x - c.x)) / dnm;
+    if !number::between(t1, 0.0 - epsilon, 1.0 + epsilon, None) {
+        return None;
+    }
+
+    Some(LineIntersection {
+        x: a.x + t0 * (b.x - a.x),
+        y: a.y + t0 * (b.y - a.y),
+        t0: math::clamp(t0, 0.0, 1.0),
+        t1: math::clamp(t1, 0.0, 1.0),
+    })
+}
+
+pub fn closest_point_to_segment(c: &Point, a: &Point, b: &Point) -> Option<Point> {
+    let dx = b.x - a.x;
+    let dy = b.y - a.y;
+
+    if dx == 0.0 && dy == 0.0 {
+        return None;
+    }
+
+    let u = (((c.x - a.x) * dx) + ((c.y - a.y) * dy)) / (dx * dx + dy * dy);
+    if u < 0.0 {
+        Some(*a)
+    } else if u > 1.0 {
+        Some(*b)
+    } else {
+        Some(Point { x: a.x + (u * dx), y: a.y + (u * dy) })
+    }
+}
+
+/// The shortest distance between the segments `ab` and `cd`, zero if they intersect
+pub fn segment_distance(a: &Point, b: &Point, c: &Point, d: &Point) -> f64 {
+    if line_segment_intersection(a, b, c, d, None).is_some() {
+        return 0.0;
+    }
+
+    let distance = |point: &Point, start: &Point, end: &Point| {
+        let closest = closest_point_to_segment(point, start, end).unwrap_or(*start);
+        f64::hypot(point.x - closest.x, point.y - closest.y)
+    };
+
+    [distance(a, c, d), distance(b, c, d), distance(c, a, b), distance(d, a, b)]
+        .into_iter()
+        .fold(f64::INFINITY, f64::min)
+}
+
+pub fn polygon_centroid(points: &[Point]) -> Point {
+    let n = points.len();
+
+    if n == 0 {
+        return Point { x: 0.0, y: 0.0 };
+    }
+
+    let mut x = 0.0;
+    let mut y = 0.0;
+    let mut a = 0.0;
+
+    let Point { x: mut x0, y: mut y0 } = points[n - 1];
+    for &Point { x: x1, y: y1 } in points {
+        let z = (x0 * y1) - (x1 * y0);
+        x += (x0 + x1) * z;
+        y += (y0 + y1) * z;
+        x0 = x1;
+        y0 = y1;
+        a += z;
+    }
+
+    a *= 3.0;
+    x /= a;
+    y /= a;
+
+    Point { x, y }
+}
+
+pub fn approximate_vertex_density(radius: f64, epsilon: Option<f64>) -> usize {
+    let epsilon = epsilon.unwrap_or(1.0);
+    (std::f64::consts::PI / (2.0 * (epsilon / radius)).sqrt()).ceil() as usize
+}
+
+pub fn polygon_signed_area(points: &[Point]) -> f64 {
+    let n = points.len();
+
+    if n < 3 {
+        return 0.0;
+    }
+
+    let mut area = 0.0;
+
+    let mut j = n - 1;
+    for i in 0..n {
+        area += (points[j].x * points[i].y) - (points[i].x * points[j].y);
+        j = i;
+    }
+
+    area / 2.0
+}
+
+pub fn polygon_contains_point(points: &[Point], point: &Point) -> bool {
+    let n = points.len();
+
+    if n < 3 {
+        return false;
+    }
+
+    let mut inside = false;
+
+    let mut j = n - 1;
+    for i in 0..n {
+        let a = &points[i];
+        let b = &points[j];
+
+        if ((a.y > point.y) != (b.y > point.y)) && (point.x < ((b.x - a.x) * (point.y - a.y) / (b.y - a.y)) + a.x) {
+            inside = !inside;
+        }
+
+        j = i;
+    }
+
+    inside
+}
+
+pub fn rotate_point(point: &Point, origin: &Point, radians: f64) -> Point {
+    let (sin, cos) = radians.sin_cos();
+    let dx = point.x - origin.x;
+    let dy = point.y - origin.y;
+
+    Point { x: origin.x + (dx * cos) - (dy * sin), y: origin.y + (dx * sin) + (dy * cos) }
+}
+
+pub fn polygons_bounds(polygons: &[Vec<Point>]) -> Rectangle {
+    let mut points = polygons.iter().flatten().peekable();
+
+    if points.peek().is_none() {
+        return Rectangle::new(0.0, 0.0, 0.0, 0.0);
+    }
+
+    let (mut x0, mut y0, mut x1, mut y1) = (f64::INFINITY, f64::INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY);
+    for Point { x, y } in points {
+        x0 = x0.min(*x);
+        y0 = y0.min(*y);
+        x1 = x1.max(*x);
+        y1 = y1.max(*y);
+    }
+
+    Rectangle::new(x0, y0, x1 - x0, y1 - y0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square() -> Vec<Point> {
+        vec![Point::new(0.0, 0.0), Point::new(10.0, 0.0), Point::new(10.0, 10.0), Point::new(0.0, 10.0)]
+    }
+
+    #[test]
+    fn segments_crossing_intersect() {
+        let intersection = line_segment_intersection(
+            &Point::new(0.0, 0.0),
+            &Point::new(10.0, 10.0),
+            &Point::new(0.0, 10.0),
+            &Point::new(10.0, 0.0),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!((intersection.x, intersection.y), (5.0, 5.0));
+        assert_eq!((intersection.t0, intersection.t1), (0.5, 0.5));
+    }
+
+    #[test]
+    fn segments_touching_at_an_end_intersect() {
+        let intersection = line_segment_intersection(
+            &Point::new(0.0, 0.0),
+            &Point::new(5.0, 0.0),
+            &Point::new(5.0, -5.0),
+            &Point::new(5.0, 5.0),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(intersection.t0, 1.0);
+    }
+
+    #[test]
+    fn segments_apart_do_not_intersect() {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(4.0, 4.0);
+
+        assert!(line_segment_intersection(&a, &b, &Point::new(0.0, 10.0), &Point::new(10.0, 0.0), None).is_none());
+        assert!(line_segment_intersection(&a, &b, &Point::new(1.0, 0.0), &Point::new(5.0, 4.0), None).is_none());
+        assert!(line_segment_intersection(&a, &a, &Point::new(0.0, 1.0), &Point::new(1.0, 0.0), None).is_none());
+    }
+
+    #[test]
+    fn segment_distance_is_between_the_closest_points() {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(10.0, 0.0);
+
+        assert_eq!(segment_distance(&a, &b, &Point::new(5.0, -5.0), &Point::new(5.0, 5.0)), 0.0);
+        assert_eq!(segment_distance(&a, &b, &Point::new(5.0, 3.0), &Point::new(5.0, 8.0)), 3.0);
+        assert_eq!(segment_distance(&a, &b, &Point::new(13.0, 4.0), &Point::new(20.0, 4.0)), 5.0);
+        assert_eq!(segment_distance(&a, &a, &Point::new(0.0, 2.0), &Point::new(0.0, 2.0)), 2.0);
+    }
+
+    #[test]
+    fn lines_intersect_beyond_their_segments() {
+        let intersection = line_line_intersection(
+            &Point::new(0.0, 0.0),
+            &Point::new(1.0, 0.0),
+            &Point::new(5.0, -1.0),
+            &Point::new(5.0, 1.0),
+        );
+
+        assert!(intersection.is_some_and(|intersection| intersection.x == 5.0 && intersection.t0 == 5.0));
+    }
+
+    #[test]
+    fn orientation_of_points() {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(10.0, 0.0);
+
+        assert!(orient_2d_fast(&a, &b, &Point::new(5.0, 5.0)) < 0.0);
+        assert!(orient_2d_fast(&a, &b, &Point::new(5.0, -5.0)) > 0.0);
+        assert_eq!(orient_2d_fast(&a, &b, &Point::new(20.0, 0.0)), 0.0);
+    }
+
+    #[test]
+    fn closest_point_is_clamped_to_the_segment() {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(10.0, 0.0);
+
+        assert_eq!(closest_point_to_segment(&Point::new(5.0, 5.0), &a, &b).map(|p| (p.x, p.y)), Some((5.0, 0.0)));
+        assert_eq!(closest_point_to_segment(&Point::new(-5.0, 5.0), &a, &b).map(|p| (p.x, p.y)), Some((0.0, 0.0)));
+        assert_eq!(closest_point_to_segment(&Point::new(15.0, 5.0), &a, &b).map(|p| (p.x, p.y)), Some((10.0, 0.0)));
+        assert!(closest_point_to_segment(&Point::new(5.0, 5.0), &a, &a).is_none());
+    }
+
+    #[test]
+    fn polygon_area_and_centroid() {
+        let mut points = square();
+
+        assert_eq!(polygon_signed_area(&points), 100.0);
+        assert_eq!(polygon_centroid(&points).round().x, 5.0);
+        assert_eq!(polygon_centroid(&points).round().y, 5.0);
+
+        points.reverse();
+        assert_eq!(polygon_signed_area(&points), -100.0);
+        assert_eq!(polygon_signed_area(&points[..2]), 0.0);
+    }
+
+    #[test]
+    fn polygon_contains_points() {
+        let points = square();
+
+        assert!(polygon_contains_point(&points, &Point::new(5.0, 5.0)));
+        assert!(!polygon_contains_point(&points, &Point::new(15.0, 5.0)));
+        assert!(!polygon_contains_point(&points, &Point::new(5.0, -1.0)));
+        assert!(!polygon_contains_point(&points[..2], &Point::new(5.0, 0.0)));
+    }
+
+    #[test]
+    fn rotate_a_quarter_turn() {
+        let point = rotate_point(&Point::new(10.0, 0.0), &Point::new(0.0, 0.0), std::f64::consts::FRAC_PI_2);
+
+        assert!(point.close(Point::new(0.0, 10.0)));
+    }
+
+    #[test]
+    fn bounds_of_polygons() {
+        let bounds = polygons_bounds(&[square(), vec![Point::new(-5.0, 20.0)]]);
+
+        assert_eq!((bounds.x, bounds.y, bounds.width, bounds.height), (-5.0, 0.0, 15.0, 20.0));
+
+        let bounds = polygons_bounds(&[]);
+        assert_eq!((bounds.width, bounds.height), (0.0, 0.0));
+    }
+
+    #[test]
+    fn vertex_density_grows_with_radius() {
+        assert!(approximate_vertex_density(100.0, None) < approximate_vertex_density(1000.0, None));
+        assert!(approximate_vertex_density(100.0, Some(0.1)) > approximate_vertex_density(100.0, None));
+    }
+}
@@ -7,5 +7,5 @@ pub fn clamp(num: f64, min: f64, max: f64) -> f64 {
 pub fn normalize_radians(radians: f64) -> f64 {
     let pi = f64::consts::PI;
     let pi2 = pi * 2.0;
-    return radians - (pi2 * f64::floor((radians + pi) / pi2));
+    radians - (pi2 * f64::floor((radians + pi) / pi2))
 }
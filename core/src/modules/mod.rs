@@ -0,0 +1,4 @@
+pub mod geometry;
+pub mod math;
+pub mod number;
+pub mod search;
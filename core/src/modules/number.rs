@@ -3,8 +3,8 @@ pub fn between(num: f64, a: f64, b: f64, inclusive: Option<bool>) -> bool {
     let min = f64::min(a, b);
     let max = f64::max(a, b);
     if inclusive {
-        return (num >= min) && (num <= max);
+        (num >= min) && (num <= max)
     } else {
-        return (num > min) && (num < max);
+        (num > min) && (num < max)
     }
 }
@@ -0,0 +1,108 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    hash::Hash,
+};
+
+/// A path is a list of nodes, each with the cost to reach it from the start
+pub enum SearchResult<N> {
+    /// The path to the goal
+    Found(Vec<(N, u32)>),
+    /// The goal couldn't be reached, contains the path to the node closest to the goal by heuristic
+    NotFound(Vec<(N, u32)>),
+}
+
+/// An A* search that can be paused after a number of expansions and resumed later
+pub struct Search<N> {
+    // Each entry is the node, the index of its parent, and the cost to reach it
+    nodes: Vec<(N, usize, u32)>,
+    indices: HashMap<N, usize>,
+    open: BinaryHeap<Reverse<(u32, Reverse<u32>, usize)>>,
+    closest: (u32, usize),
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    pub fn new(start: &N, estimated: u32) -> Self {
+        Search {
+            nodes: vec![(start.clone(), usize::MAX, 0)],
+            indices: HashMap::from([(start.clone(), 0)]),
+            open: BinaryHeap::from([Reverse((estimated, Reverse(0), 0))]),
+            closest: (u32::MAX, 0),
+        }
+    }
+
+    /// Expands up to `budget` nodes, returning `None` if the search isn't finished yet
+    pub fn step<FN, IN, FH, FS>(
+        &mut self,
+        budget: &mut usize,
+        mut successors: FN,
+        mut heuristic: FH,
+        mut success: FS,
+    ) -> Option<SearchResult<N>>
+    where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = (N, u32)>,
+        FH: FnMut(&N) -> u32,
+        FS: FnMut(&N) -> bool,
+    {
+        while *budget > 0 {
+            let Some(Reverse((_estimated, Reverse(cost), index))) = self.open.pop() else {
+                return Some(SearchResult::NotFound(self.reverse_path(self.closest.1)));
+            };
+
+            if cost > self.nodes[index].2 {
+                continue;
+            }
+
+            *budget -= 1;
+
+            let node = self.nodes[index].0.clone();
+            if success(&node) {
+                return Some(SearchResult::Found(self.reverse_path(index)));
+            }
+
+            let remaining = heuristic(&node);
+            if remaining < self.closest.0 {
+                self.closest = (remaining, index);
+            }
+
+            for (successor, step) in successors(&node) {
+                let new_cost = cost + step;
+
+                let successor_index = match self.indices.get(&successor) {
+                    Some(&i) if self.nodes[i].2 <= new_cost => continue,
+                    Some(&i) => {
+                        self.nodes[i] = (successor.clone(), index, new_cost);
+                        i
+                    }
+                    None => {
+                        self.nodes.push((successor.clone(), index, new_cost));
+                        self.indices.insert(successor.clone(), self.nodes.len() - 1);
+                        self.nodes.len() - 1
+                    }
+                };
+
+                self.open.push(Reverse((new_cost + heuristic(&successor), Reverse(new_cost), successor_index)));
+            }
+        }
+
+        None
+    }
+
+    /// The path to the expanded node closest to the goal by heuristic
+    pub fn closest_path(&self) -> Vec<(N, u32)> {
+        self.reverse_path(self.closest.1)
+    }
+
+    fn reverse_path(&self, mut index: usize) -> Vec<(N, u32)> {
+        let mut path = Vec::new();
+
+        while index != usize::MAX {
+            path.push((self.nodes[index].0.clone(), self.nodes[index].2));
+            index = self.nodes[index].1;
+        }
+
+        path.reverse();
+        path
+    }
+}
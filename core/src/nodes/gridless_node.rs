@@ -1,7 +1,4 @@
-use crate::{
-    traits::{JsDeserialize, JsHelper, JsSerialize, Node},
-    types::{GridOffset2D, GridOffset3D},
-};
+use crate::traits::Node;
 use std::hash::Hash;
 
 #[derive(Clone, Copy)]
@@ -22,12 +19,16 @@ impl GridlessNode {
 }
 
 impl Node for GridlessNode {
-    fn at_node(&self, _other: &Self) -> bool {
-        false
+    fn at_node(&self, other: &Self) -> bool {
+        self.i == other.i && self.j == other.j && self.k == other.k
     }
 
-    fn get_distance(&self, _other: &Self) -> u32 {
-        0
+    fn get_distance(&self, other: &Self) -> u32 {
+        let di = (self.i - other.i) as f64;
+        let dj = (self.j - other.j) as f64;
+        let dk = (self.k - other.k) as f64;
+
+        (di * di + dj * dj + dk * dk).sqrt().floor() as u32
     }
 
     fn get_elevation(&self) -> i32 {
@@ -38,7 +39,5 @@ impl Node for GridlessNode {
         Vec::new()
     }
 
-    fn set_diagonal(&mut self, _diagonal: bool) {
-        todo!()
-    }
+    fn set_diagonals(&mut self, _diagonals: i32) {}
 }
@@ -1,7 +1,7 @@
 use crate::{
     grids::HexagonalGrid,
-    traits::Node,
-    types::{HexagonalGridCube2D, HexagonalGridCube3D},
+    traits::{node::COST_SCALE, Node},
+    types::HexagonalGridCube2D,
 };
 use std::hash::Hash;
 
@@ -39,7 +39,7 @@ impl Node for HexagonalNode {
             (n, d) = (d, n);
         }
 
-        n + ((d + if self.d { 1 } else { 0 }) / 2)
+        (n + ((d + if self.d { 1 } else { 0 }) / 2)) * COST_SCALE
     }
 
     fn get_elevation(&self) -> i32 {
@@ -50,30 +50,30 @@ impl Node for HexagonalNode {
         let HexagonalNode { q, r, s, k, d } = *self;
 
         vec![
-            (HexagonalNode::new(q - 1, r, s + 1, k - 1, !d), if d { 2 } else { 1 }),
-            (HexagonalNode::new(q - 1, r, s + 1, k, d), 1),
-            (HexagonalNode::new(q - 1, r, s + 1, k + 1, !d), if d { 2 } else { 1 }),
-            (HexagonalNode::new(q - 1, r + 1, s, k - 1, !d), if d { 2 } else { 1 }),
-            (HexagonalNode::new(q - 1, r + 1, s, k, d), 1),
-            (HexagonalNode::new(q - 1, r + 1, s, k + 1, !d), if d { 2 } else { 1 }),
-            (HexagonalNode::new(q, r - 1, s + 1, k - 1, !d), if d { 2 } else { 1 }),
-            (HexagonalNode::new(q, r - 1, s + 1, k, d), 1),
-            (HexagonalNode::new(q, r - 1, s + 1, k + 1, !d), if d { 2 } else { 1 }),
-            (HexagonalNode::new(q, r, s, k - 1, d), 1),
-            (HexagonalNode::new(q, r, s, k + 1, d), 1),
-            (HexagonalNode::new(q, r + 1, s - 1, k - 1, !d), if d { 2 } else { 1 }),
-            (HexagonalNode::new(q, r + 1, s - 1, k, d), 1),
-            (HexagonalNode::new(q, r + 1, s - 1, k + 1, !d), if d { 2 } else { 1 }),
-            (HexagonalNode::new(q + 1, r - 1, s, k - 1, !d), if d { 2 } else { 1 }),
-            (HexagonalNode::new(q + 1, r - 1, s, k, d), 1),
-            (HexagonalNode::new(q + 1, r - 1, s, k + 1, !d), if d { 2 } else { 1 }),
-            (HexagonalNode::new(q + 1, r, s - 1, k - 1, !d), if d { 2 } else { 1 }),
-            (HexagonalNode::new(q + 1, r, s - 1, k, d), 1),
-            (HexagonalNode::new(q + 1, r, s - 1, k + 1, !d), if d { 2 } else { 1 }),
+            (HexagonalNode::new(q - 1, r, s + 1, k - 1, !d), if d { 2 * COST_SCALE } else { COST_SCALE }),
+            (HexagonalNode::new(q - 1, r, s + 1, k, d), COST_SCALE),
+            (HexagonalNode::new(q - 1, r, s + 1, k + 1, !d), if d { 2 * COST_SCALE } else { COST_SCALE }),
+            (HexagonalNode::new(q - 1, r + 1, s, k - 1, !d), if d { 2 * COST_SCALE } else { COST_SCALE }),
+            (HexagonalNode::new(q - 1, r + 1, s, k, d), COST_SCALE),
+            (HexagonalNode::new(q - 1, r + 1, s, k + 1, !d), if d { 2 * COST_SCALE } else { COST_SCALE }),
+            (HexagonalNode::new(q, r - 1, s + 1, k - 1, !d), if d { 2 * COST_SCALE } else { COST_SCALE }),
+            (HexagonalNode::new(q, r - 1, s + 1, k, d), COST_SCALE),
+            (HexagonalNode::new(q, r - 1, s + 1, k + 1, !d), if d { 2 * COST_SCALE } else { COST_SCALE }),
+            (HexagonalNode::new(q, r, s, k - 1, d), COST_SCALE),
+            (HexagonalNode::new(q, r, s, k + 1, d), COST_SCALE),
+            (HexagonalNode::new(q, r + 1, s - 1, k - 1, !d), if d { 2 * COST_SCALE } else { COST_SCALE }),
+            (HexagonalNode::new(q, r + 1, s - 1, k, d), COST_SCALE),
+            (HexagonalNode::new(q, r + 1, s - 1, k + 1, !d), if d { 2 * COST_SCALE } else { COST_SCALE }),
+            (HexagonalNode::new(q + 1, r - 1, s, k - 1, !d), if d { 2 * COST_SCALE } else { COST_SCALE }),
+            (HexagonalNode::new(q + 1, r - 1, s, k, d), COST_SCALE),
+            (HexagonalNode::new(q + 1, r - 1, s, k + 1, !d), if d { 2 * COST_SCALE } else { COST_SCALE }),
+            (HexagonalNode::new(q + 1, r, s - 1, k - 1, !d), if d { 2 * COST_SCALE } else { COST_SCALE }),
+            (HexagonalNode::new(q + 1, r, s - 1, k, d), COST_SCALE),
+            (HexagonalNode::new(q + 1, r, s - 1, k + 1, !d), if d { 2 * COST_SCALE } else { COST_SCALE }),
         ]
     }
 
-    fn set_diagonal(&mut self, diagonal: bool) {
-        self.d = diagonal
+    fn set_diagonals(&mut self, diagonals: i32) {
+        self.d = diagonals % 2 != 0
     }
 }
@@ -0,0 +1,129 @@
+use crate::{
+    enums::GridDiagonals,
+    traits::{node::COST_SCALE, Node},
+};
+use std::hash::Hash;
+
+#[derive(Clone, Copy)]
+#[derive(Debug)]
+#[derive(Hash)]
+#[derive(PartialEq, Eq)]
+#[derive(PartialOrd, Ord)]
+pub struct SquareNode {
+    pub i: i32,
+    pub j: i32,
+    pub k: i32,
+    pub d: bool,
+    pub diagonals: GridDiagonals,
+}
+
+impl SquareNode {
+    pub fn new(i: i32, j: i32, k: i32, d: bool, diagonals: GridDiagonals) -> Self {
+        Self { i, j, k, d, diagonals }
+    }
+
+    #[allow(unused_assignments)]
+    pub fn from(&mut self, origin: &SquareNode) {
+        let mut di = (self.i - origin.i).unsigned_abs();
+        let mut dj = (self.j - origin.j).unsigned_abs();
+        if di < dj {
+            (di, dj) = (dj, di);
+        }
+        let mut dk = (self.k - origin.k).unsigned_abs();
+        if dj < dk {
+            (dj, dk) = (dk, dj);
+        }
+        if di < dj {
+            (di, dj) = (dj, di);
+        }
+
+        let nd = dj as f64 + (0.5 * dk as f64) + if origin.d { 1.0 } else { 0.0 };
+        let nd = (nd / 2.0).floor() as u32;
+        self.d = (nd % 2) == 1;
+    }
+
+    fn get_step_cost(&self, axes: u32) -> u32 {
+        match (self.diagonals, axes) {
+            (_, 1) => COST_SCALE,
+            (GridDiagonals::Equidistant, _) => COST_SCALE,
+            (GridDiagonals::Exact, axes) => (COST_SCALE as f64 * (axes as f64).sqrt()).round() as u32,
+            (GridDiagonals::Approximate, axes) => COST_SCALE + ((axes - 1) * COST_SCALE / 2),
+            (GridDiagonals::Rectilinear | GridDiagonals::Illegal, axes) => axes * COST_SCALE,
+            (GridDiagonals::Alternating1 | GridDiagonals::Alternating2, _) => {
+                if self.d {
+                    2 * COST_SCALE
+                } else {
+                    COST_SCALE
+                }
+            }
+        }
+    }
+}
+
+impl Node for SquareNode {
+    fn at_node(&self, other: &Self) -> bool {
+        self.i == other.i && self.j == other.j && self.k == other.k
+    }
+
+    fn get_distance(&self, other: &Self) -> u32 {
+        let mut di = (self.i - other.i).unsigned_abs();
+        let mut dj = (self.j - other.j).unsigned_abs();
+        if di < dj {
+            (di, dj) = (dj, di);
+        }
+        let mut dk = (self.k - other.k).unsigned_abs();
+        if dj < dk {
+            (dj, dk) = (dk, dj);
+        }
+        if di < dj {
+            (di, dj) = (dj, di);
+        }
+
+        match self.diagonals {
+            GridDiagonals::Equidistant => di * COST_SCALE,
+            GridDiagonals::Exact | GridDiagonals::Approximate => {
+                ((di - dj) * self.get_step_cost(1)) + ((dj - dk) * self.get_step_cost(2)) + (dk * self.get_step_cost(3))
+            }
+            GridDiagonals::Rectilinear | GridDiagonals::Illegal => (di + dj + dk) * COST_SCALE,
+            GridDiagonals::Alternating1 | GridDiagonals::Alternating2 => {
+                let nd = dj as f64 + (0.5 * dk as f64) + if self.d { 1.0 } else { 0.0 };
+                let nd = (nd / 2.0).floor() as u32;
+                (di + nd) * COST_SCALE
+            }
+        }
+    }
+
+    fn get_elevation(&self) -> i32 {
+        self.k
+    }
+
+    fn get_neighbors(&self) -> Vec<(Self, u32)> {
+        let SquareNode { i, j, k, d, diagonals } = *self;
+        let mut neighbors = Vec::with_capacity(26);
+
+        for di in -1..=1 {
+            for dj in -1..=1 {
+                for dk in -1..=1 {
+                    let axes = [di, dj, dk].iter().filter(|delta| **delta != 0).count() as u32;
+
+                    if axes == 0 || (axes > 1 && diagonals == GridDiagonals::Illegal) {
+                        continue;
+                    }
+
+                    let d = if axes > 1 && diagonals.is_alternating() { !d } else { d };
+                    neighbors.push((SquareNode::new(i + di, j + dj, k + dk, d, diagonals), self.get_step_cost(axes)));
+                }
+            }
+        }
+
+        neighbors
+    }
+
+    fn set_diagonals(&mut self, diagonals: i32) {
+        self.d = match self.diagonals {
+            GridDiagonals::Alternating1 => diagonals % 2 != 0,
+            GridDiagonals::Alternating2 => diagonals % 2 == 0,
+            _ => false,
+        }
+    }
+}
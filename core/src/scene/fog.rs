@@ -0,0 +1,230 @@
+use crate::{
+    modules::geometry,
+    types::{Point, Rectangle},
+};
+
+/// The explored areas of the scene, one bit for every pixel of the fog exploration texture
+pub struct Fog {
+    pub bounds: Rectangle,
+    pub resolution: f64,
+    pub width: i32,
+    pub height: i32,
+    bits: Vec<u64>,
+}
+
+impl Fog {
+    /// A fog with nothing explored, `width` and `height` are the size of the texture in pixels
+    pub fn new(bounds: Rectangle, resolution: f64, width: i32, height: i32) -> Self {
+        let pixels = (width.max(0) as usize) * (height.max(0) as usize);
+        Fog { bounds, resolution, width, height, bits: vec![0; pixels.div_ceil(64)] }
+    }
+
+    /// A fog read from a mask of the explored areas, `pixels` holds its rows one after another
+    ///
+    /// Each pixel is either one byte or four (RGBA), and it's explored if its first byte isn't zero.
+    pub fn from_pixels(bounds: Rectangle, resolution: f64, width: i32, height: i32, pixels: &[u8]) -> Self {
+        let count = (width.max(0) as usize) * (height.max(0) as usize);
+        let channels = if count > 0 && pixels.len() >= count * 4 { 4 } else { 1 };
+
+        let mut fog = Fog::new(bounds, resolution, width, height);
+        fog.update(0, 0, width, height, pixels, channels);
+        fog
+    }
+
+    /// A fog where the pixels whose centers are inside any of `polygons` are explored
+    pub fn from_polygons(bounds: Rectangle, resolution: f64, polygons: &[Vec<Point>]) -> Self {
+        let width = (bounds.width * resolution).ceil() as i32;
+        let height = (bounds.height * resolution).ceil() as i32;
+        let mut fog = Fog::new(bounds, resolution, width, height);
+
+        for polygon in polygons {
+            let rect = geometry::polygons_bounds(std::slice::from_ref(polygon));
+            let x0 = (((rect.left() - bounds.x) * resolution).floor() as i32).max(0);
+            let y0 = (((rect.top() - bounds.y) * resolution).floor() as i32).max(0);
+            let x1 = (((rect.right() - bounds.x) * resolution).ceil() as i32).min(width);
+            let y1 = (((rect.bottom() - bounds.y) * resolution).ceil() as i32).min(height);
+
+            for y in y0..y1 {
+                for x in x0..x1 {
+                    let center = Point {
+                        x: bounds.x + ((x as f64 + 0.5) / resolution),
+                        y: bounds.y + ((y as f64 + 0.5) / resolution),
+                    };
+                    if geometry::polygon_contains_point(polygon, &center) {
+                        fog.set_pixel(x, y, true);
+                    }
+                }
+            }
+        }
+
+        fog
+    }
+
+    /// Copies a `width` by `height` block of pixels into the fog with its top left corner at (`x`, `y`)
+    ///
+    /// `pixels` holds the rows of the block one after another, `channels` bytes per pixel, and a pixel is explored if
+    /// its first channel isn't zero. Anything outside of the fog is ignored.
+    pub fn update(&mut self, x: i32, y: i32, width: i32, height: i32, pixels: &[u8], channels: usize) {
+        for row in 0..height {
+            if y + row < 0 || y + row >= self.height {
+                continue;
+            }
+
+            for column in 0..width {
+                if x + column < 0 || x + column >= self.width {
+                    continue;
+                }
+
+                let index = ((row * width) + column) as usize * channels;
+                if let Some(pixel) = pixels.get(index) {
+                    self.set_pixel(x + column, y + row, *pixel != 0);
+                }
+            }
+        }
+    }
+
+    pub fn is_point_explored(&self, Point { mut x, mut y }: Point) -> bool {
+        if !self.bounds.contains(x, y) {
+            return false;
+        }
+
+        x -= self.bounds.x;
+        y -= self.bounds.y;
+
+        let x1 = (x * self.resolution).floor() as i32;
+        let y1 = (y * self.resolution).floor() as i32;
+
+        for y in (y1 - 1).max(0)..(y1 + 2).min(self.height) {
+            for x in (x1 - 1).max(0)..(x1 + 2).min(self.width) {
+                if self.get_pixel(x, y) {
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+
+    /// The fraction of `rect` that has been explored
+    ///
+    /// Points are sampled on a 5x5 lattice and only the ones inside the ellipse inscribed in `rect` are counted, so
+    /// the corners of a hexagon's bounding box, which belong to its neighbors, are left out.
+    pub fn get_explored_coverage(&self, rect: &Rectangle) -> f64 {
+        let mut samples = 0;
+        let mut explored = 0;
+
+        for v in [-0.8, -0.4, 0.0, 0.4, 0.8] {
+            for u in [-0.8, -0.4, 0.0, 0.4, 0.8] {
+                if (u * u) + (v * v) > 1.0 {
+                    continue;
+                }
+
+                samples += 1;
+                let x = rect.x + (rect.width * (u + 1.0) / 2.0);
+                let y = rect.y + (rect.height * (v + 1.0) / 2.0);
+                if self.is_pixel_explored(Point { x, y }) {
+                    explored += 1;
+                }
+            }
+        }
+
+        explored as f64 / samples as f64
+    }
+
+    fn is_pixel_explored(&self, Point { x, y }: Point) -> bool {
+        if !self.bounds.contains(x, y) {
+            return false;
+        }
+
+        let x = ((x - self.bounds.x) * self.resolution).floor() as i32;
+        let y = ((y - self.bounds.y) * self.resolution).floor() as i32;
+        self.get_pixel(x.clamp(0, self.width - 1), y.clamp(0, self.height - 1))
+    }
+
+    fn get_pixel(&self, x: i32, y: i32) -> bool {
+        let index = ((y * self.width) + x) as usize;
+        self.bits.get(index / 64).is_some_and(|bits| bits & (1 << (index % 64)) != 0)
+    }
+
+    fn set_pixel(&mut self, x: i32, y: i32, explored: bool) {
+        let index = ((y * self.width) + x) as usize;
+        if explored {
+            self.bits[index / 64] |= 1 << (index % 64);
+        } else {
+            self.bits[index / 64] &= !(1 << (index % 64));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A fog covering 0..100 by 0..100 with the left `explored` pixels of each row explored
+    fn fog(explored: i32) -> Fog {
+        let pixels: Vec<u8> = (0..100).flat_map(|_| (0..100).map(|x| if x < explored { 255 } else { 0 })).collect();
+        Fog::from_pixels(Rectangle::new(0.0, 0.0, 100.0, 100.0), 1.0, 100, 100, &pixels)
+    }
+
+    #[test]
+    fn coverage_counts_the_explored_samples() {
+        let rect = Rectangle::new(0.0, 0.0, 100.0, 100.0);
+
+        assert_eq!(fog(0).get_explored_coverage(&rect), 0.0);
+        assert_eq!(fog(100).get_explored_coverage(&rect), 1.0);
+
+        let half = fog(50).get_explored_coverage(&rect);
+        assert!(half > 0.3 && half < 0.7, "{half}");
+
+        // The center point is explored even though most of the rectangle isn't
+        assert!(fog(55).is_point_explored(Point { x: 50.0, y: 50.0 }));
+        assert!(fog(55).get_explored_coverage(&rect) < 0.75);
+    }
+
+    #[test]
+    fn coverage_outside_the_bounds_is_unexplored() {
+        assert_eq!(fog(100).get_explored_coverage(&Rectangle::new(200.0, 200.0, 100.0, 100.0)), 0.0);
+
+        let partial = fog(100).get_explored_coverage(&Rectangle::new(60.0, 0.0, 100.0, 100.0));
+        assert!(partial > 0.0 && partial < 0.5, "{partial}");
+    }
+
+    #[test]
+    fn updates_only_change_their_block() {
+        let mut fog = fog(100);
+
+        // An RGBA block with its left half unexplored and its right half outside of the fog
+        let pixels: Vec<u8> =
+            (0..20).flat_map(|_| (0..20).flat_map(|x| [if x < 10 { 0 } else { 255 }, 0, 0, 255])).collect();
+        fog.update(90, 40, 20, 20, &pixels, 4);
+
+        assert!(fog.is_point_explored(Point { x: 50.0, y: 50.0 }));
+        assert!(fog.is_point_explored(Point { x: 99.5, y: 39.5 }));
+        assert!(fog.is_point_explored(Point { x: 99.5, y: 61.5 }));
+        assert!(!fog.is_point_explored(Point { x: 99.5, y: 50.5 }));
+        assert_eq!(fog.get_explored_coverage(&Rectangle::new(10.0, 10.0, 80.0, 80.0)), 1.0);
+    }
+
+    #[test]
+    fn rgba_pixels_use_the_first_channel() {
+        let pixels: Vec<u8> = (0..4).flat_map(|x| [if x % 2 == 0 { 255 } else { 0 }, 0, 0, 255]).collect();
+        let fog = Fog::from_pixels(Rectangle::new(0.0, 0.0, 40.0, 10.0), 0.1, 4, 1, &pixels);
+
+        assert_eq!(fog.get_explored_coverage(&Rectangle::new(0.0, 0.0, 10.0, 10.0)), 1.0);
+        assert_eq!(fog.get_explored_coverage(&Rectangle::new(10.0, 0.0, 10.0, 10.0)), 0.0);
+        assert_eq!(fog.get_explored_coverage(&Rectangle::new(20.0, 0.0, 10.0, 10.0)), 1.0);
+    }
+
+    #[test]
+    fn polygons_explore_the_pixels_they_cover() {
+        let bounds = Rectangle::new(100.0, 100.0, 1000.0, 1000.0);
+        let triangle = vec![Point::new(100.0, 100.0), Point::new(1100.0, 100.0), Point::new(100.0, 1100.0)];
+        let fog = Fog::from_polygons(bounds, 0.1, &[triangle]);
+
+        assert_eq!((fog.width, fog.height), (100, 100));
+        assert!(fog.is_point_explored(Point::new(200.0, 200.0)));
+        assert!(!fog.is_point_explored(Point::new(1000.0, 1000.0)));
+        assert_eq!(fog.get_explored_coverage(&Rectangle::new(150.0, 150.0, 100.0, 100.0)), 1.0);
+        assert_eq!(fog.get_explored_coverage(&Rectangle::new(900.0, 900.0, 100.0, 100.0)), 0.0);
+    }
+}
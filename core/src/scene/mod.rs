@@ -0,0 +1,11 @@
+pub mod fog;
+pub mod regions;
+pub mod scene_state;
+pub mod tokens;
+pub mod walls;
+
+pub use self::fog::Fog;
+pub use self::regions::Regions;
+pub use self::scene_state::SceneState;
+pub use self::tokens::Tokens;
+pub use self::walls::Walls;
@@ -0,0 +1,92 @@
+use crate::types::{ElevatedPoint, GridOffset3D, MovementProfile, Point, Region};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+pub struct Regions {
+    map: HashMap<String, Rc<RefCell<Region>>>,
+    cells: RefCell<HashMap<String, HashMap<GridOffset3D, f64>>>,
+}
+
+impl Regions {
+    pub fn new(regions: Vec<Region>) -> Self {
+        let mut map = HashMap::new();
+
+        for region in regions {
+            let region = Rc::new(RefCell::new(region));
+            map.insert(region.borrow().id.clone(), region.clone());
+        }
+
+        Regions { map, cells: RefCell::new(HashMap::new()) }
+    }
+
+    pub fn add_region(&mut self, region: Region) {
+        let region = Rc::new(RefCell::new(region));
+        self.map.insert(region.borrow().id.clone(), region.clone());
+        self.cells.borrow_mut().clear();
+    }
+
+    pub fn delete_region(&mut self, id: &str) {
+        self.map.remove(id);
+        self.cells.borrow_mut().clear();
+    }
+
+    pub fn update_region(&mut self, region: Region) {
+        self.delete_region(&region.id);
+        self.add_region(region);
+    }
+
+    pub fn get_cost_multiplier(&self, point: ElevatedPoint, profile: &MovementProfile) -> f64 {
+        if profile.water_only && !self.is_water(point) {
+            return f64::INFINITY;
+        }
+
+        self.map
+            .values()
+            .map(|region| region.borrow())
+            .filter(|region| region.contains_point(point))
+            .filter_map(|region| region.get_cost_multiplier(profile))
+            .reduce(f64::max)
+            .unwrap_or(1.0)
+    }
+
+    pub fn get_segment_cost_multiplier(&self, a: ElevatedPoint, b: ElevatedPoint, profile: &MovementProfile) -> f64 {
+        if profile.water_only && (!self.is_water(a) || !self.is_water(b)) {
+            return f64::INFINITY;
+        }
+
+        self.map
+            .values()
+            .map(|region| region.borrow())
+            .filter(|region| region.intersects_segment(a, b))
+            .filter_map(|region| region.get_cost_multiplier(profile))
+            .reduce(f64::max)
+            .unwrap_or(1.0)
+    }
+
+    pub fn get_corners(&self, radius: f64, profile: &MovementProfile) -> Vec<Point> {
+        self.map
+            .values()
+            .map(|region| region.borrow())
+            .filter(|region| region.get_cost_multiplier(profile).is_some_and(f64::is_infinite))
+            .flat_map(|region| region.get_corners(radius))
+            .collect()
+    }
+
+    pub fn get_offset_cost_multiplier(
+        &self,
+        offset: GridOffset3D,
+        point: ElevatedPoint,
+        profile: &MovementProfile,
+    ) -> f64 {
+        if let Some(multiplier) = self.cells.borrow().get(&profile.action).and_then(|cells| cells.get(&offset)) {
+            return *multiplier;
+        }
+
+        let multiplier = self.get_cost_multiplier(point, profile);
+        self.cells.borrow_mut().entry(profile.action.clone()).or_default().insert(offset, multiplier);
+        multiplier
+    }
+
+    pub fn is_water(&self, point: ElevatedPoint) -> bool {
+        self.map.values().map(|region| region.borrow()).any(|region| region.flags.water && region.contains_point(point))
+    }
+}
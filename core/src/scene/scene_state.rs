@@ -0,0 +1,32 @@
+use crate::{
+    enums::Grid,
+    scene::{Fog, Regions, Tokens, Walls},
+    types::{Rectangle, Region, TokenDocument, Wall},
+};
+
+/// Everything in a scene that paths are found through
+pub struct SceneState {
+    pub bounds: Rectangle,
+    pub fog: Option<Fog>,
+    pub regions: Regions,
+    pub tokens: Tokens,
+    pub walls: Walls,
+}
+
+impl SceneState {
+    pub fn new(
+        bounds: Rectangle,
+        grid: &Grid,
+        walls: Vec<Wall>,
+        regions: Vec<Region>,
+        tokens: Vec<TokenDocument>,
+    ) -> Self {
+        SceneState {
+            bounds,
+            fog: None,
+            regions: Regions::new(regions),
+            tokens: Tokens::new(tokens),
+            walls: Walls::new(bounds, grid, walls),
+        }
+    }
+}
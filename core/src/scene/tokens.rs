@@ -0,0 +1,35 @@
+use crate::types::TokenDocument;
+use std::collections::HashMap;
+
+pub struct Tokens {
+    map: HashMap<String, TokenDocument>,
+}
+
+impl Tokens {
+    pub fn new(tokens: Vec<TokenDocument>) -> Self {
+        let mut map = HashMap::new();
+
+        for token in tokens {
+            map.insert(token.id.clone(), token);
+        }
+
+        Tokens { map }
+    }
+
+    pub fn add_token(&mut self, token: TokenDocument) {
+        self.map.insert(token.id.clone(), token);
+    }
+
+    pub fn delete_token(&mut self, id: &str) {
+        self.map.remove(id);
+    }
+
+    pub fn update_token(&mut self, token: TokenDocument) {
+        self.delete_token(&token.id);
+        self.add_token(token);
+    }
+
+    pub fn get_obstacles<'a>(&'a self, token: &'a TokenDocument) -> impl Iterator<Item = &'a TokenDocument> {
+        self.map.values().filter(move |other| other.id != token.id && !other.hidden)
+    }
+}
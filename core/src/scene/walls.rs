@@ -0,0 +1,735 @@
+use crate::{
+    enums::{Grid, WallDirection, WallRestrictionType, WallSenseType},
+    modules::geometry,
+    types::{ElevatedPoint, GridOffset2D, GridOffset3D, MovementProfile, Point, Quadtree, Ray, Rectangle, Wall},
+};
+use std::{collections::HashMap, f64::consts::TAU};
+
+pub struct Walls {
+    bounds: Rectangle,
+    walls: Quadtree<Wall>,
+    keys: HashMap<String, usize>,
+    /// The walls that only block some movement actions or elevations, these can't be cached
+    conditional: Quadtree<Wall>,
+    conditional_keys: HashMap<String, usize>,
+    grid: Grid,
+    /// For each grid space, a bit for every neighbor a wall that always blocks is in the way of, see [`Walls::get_direction`]
+    blocked: HashMap<GridOffset2D, u16>,
+}
+
+impl Walls {
+    pub fn new(bounds: Rectangle, grid: &Grid, walls: Vec<Wall>) -> Self {
+        let mut this = Walls {
+            bounds,
+            walls: Quadtree::new(bounds, None, None),
+            keys: HashMap::new(),
+            conditional: Quadtree::new(bounds, None, None),
+            conditional_keys: HashMap::new(),
+            grid: grid.clone(),
+            blocked: HashMap::new(),
+        };
+
+        for wall in walls {
+            this.add_wall(wall);
+        }
+
+        this
+    }
+
+    pub fn add_wall(&mut self, wall: Wall) {
+        self.delete_wall(&wall.id);
+
+        if wall.always_blocks() {
+            for offset in self.get_nearby_offsets(&wall.get_bounds()) {
+                let mut mask = 0;
+                for (direction, neighbor) in Walls::get_neighbors(offset) {
+                    if Walls::crosses(&wall, self.get_center_point(offset), self.get_center_point(neighbor)) {
+                        mask |= 1 << direction;
+                    }
+                }
+
+                if mask != 0 {
+                    *self.blocked.entry(offset).or_default() |= mask;
+                }
+            }
+        } else if wall.blocks_movement() {
+            let key = self.conditional.insert(wall.get_bounds(), wall.clone());
+            self.conditional_keys.insert(wall.id.clone(), key);
+        }
+
+        let id = wall.id.clone();
+        let key = self.walls.insert(wall.get_bounds(), wall);
+        self.keys.insert(id, key);
+    }
+
+    pub fn delete_wall(&mut self, id: &str) {
+        if let Some(key) = self.conditional_keys.remove(id) {
+            self.conditional.remove(key);
+        }
+
+        let Some(wall) = self.keys.remove(id).and_then(|key| self.walls.remove(key)) else {
+            return;
+        };
+
+        if !wall.always_blocks() {
+            return;
+        }
+
+        // Other walls could be blocking the same moves, so they're checked again
+        for offset in self.get_nearby_offsets(&wall.get_bounds()) {
+            let Some(&mask) = self.blocked.get(&offset) else {
+                continue;
+            };
+
+            let mut new_mask = mask;
+            for (direction, neighbor) in Walls::get_neighbors(offset) {
+                if mask & (1 << direction) == 0 {
+                    continue;
+                }
+
+                let start = self.get_center_point(offset);
+                let end = self.get_center_point(neighbor);
+                let ray = Ray::new(start, end);
+                if !self.walls.any(&ray.bounds(), |other| other.always_blocks() && Walls::crosses(other, start, end)) {
+                    new_mask &= !(1 << direction);
+                }
+            }
+
+            if new_mask == 0 {
+                self.blocked.remove(&offset);
+            } else {
+                self.blocked.insert(offset, new_mask);
+            }
+        }
+    }
+
+    pub fn update_wall(&mut self, wall: Wall) {
+        self.delete_wall(&wall.id);
+        self.add_wall(wall);
+    }
+
+    /// Whether a wall blocks moving between the centers of two neighboring grid spaces, at the elevation of the
+    /// bottom of each space
+    ///
+    /// Walls that always block are looked up in a table of the grid spaces next to them, only the walls that depend on
+    /// the movement action or elevation are checked for collisions.
+    pub fn check_move(&self, start: GridOffset3D, end: GridOffset3D, profile: &MovementProfile) -> bool {
+        let get_point = |offset: GridOffset3D| ElevatedPoint {
+            elevation: self.grid.get_top_left_point(offset).elevation,
+            ..self.grid.get_center_point(offset)
+        };
+
+        let Some(direction) = Walls::get_direction(start.into(), end.into()) else {
+            return self.check_collision(get_point(start), get_point(end), profile);
+        };
+
+        if self.blocked.get(&start.into()).is_some_and(|mask| mask & (1 << direction) != 0) {
+            return true;
+        }
+
+        Walls::check_collision_in(&self.conditional, get_point(start), get_point(end), profile)
+    }
+
+    pub fn check_collision(&self, start: ElevatedPoint, end: ElevatedPoint, profile: &MovementProfile) -> bool {
+        Walls::check_collision_in(&self.walls, start, end, profile)
+    }
+
+    fn check_collision_in(
+        walls: &Quadtree<Wall>,
+        start: ElevatedPoint,
+        end: ElevatedPoint,
+        profile: &MovementProfile,
+    ) -> bool {
+        let ray = Ray::new(start.into(), end.into());
+        walls.any(&ray.bounds(), |edge| {
+            if edge.blocks_profile(profile) && edge.blocks_direction(&ray.a) {
+                if let Some(intersection) =
+                    geometry::line_segment_intersection(&ray.a, &ray.b, &edge.get_a(), &edge.get_b(), None)
+                {
+                    // The elevation of the token where it crosses the wall
+                    let elevation = start.elevation + (intersection.t0 * (end.elevation - start.elevation));
+
+                    if edge.contains_elevation(elevation) {
+                        return true;
+                    }
+                }
+            }
+
+            false
+        })
+    }
+
+    /// Whether a token with a `radius` moving from `start` to `end` would touch a wall, not just cross it
+    ///
+    /// A token that's already closer to a wall than its radius, or is moving to somewhere that is, may still move as
+    /// long as it doesn't get closer to the wall along the way.
+    pub fn check_swept_collision(
+        &self,
+        start: ElevatedPoint,
+        end: ElevatedPoint,
+        radius: f64,
+        profile: &MovementProfile,
+    ) -> bool {
+        if self.check_collision(start, end, profile) {
+            return true;
+        }
+
+        let (a, b): (Point, Point) = (start.into(), end.into());
+        self.walls.any(&Ray::new(a, b).bounds().pad(radius), |edge| {
+            if !edge.blocks_profile(profile)
+                || !edge.blocks_direction(&a)
+                || !(edge.contains_elevation(start.elevation) || edge.contains_elevation(end.elevation))
+            {
+                return false;
+            }
+
+            let (c, d) = (edge.get_a(), edge.get_b());
+            let allowed = [a, b]
+                .into_iter()
+                .map(|point| geometry::segment_distance(&point, &point, &c, &d))
+                .fold(radius, f64::min);
+
+            geometry::segment_distance(&a, &b, &c, &d) < allowed - 1e-6
+        })
+    }
+
+    /// Whether a token with a `radius` at `point` would overlap a wall that blocks it from both sides at every elevation
+    pub fn check_clearance(&self, point: Point, radius: f64, profile: &MovementProfile) -> bool {
+        let area = Rectangle::new(point.x, point.y, 0.0, 0.0).pad(radius);
+
+        self.walls.any(&area, |edge| {
+            edge.blocks_profile(profile)
+                && edge.dir == WallDirection::Both
+                && edge.contains_every_elevation()
+                && geometry::segment_distance(&point, &point, &edge.get_a(), &edge.get_b()) < radius
+        })
+    }
+
+    /// Whether walls block sight from `origin` to `target`
+    ///
+    /// A limited wall only blocks sight once a second one is crossed, and proximity and distance walls only block it
+    /// depending on how far `origin` is from them.
+    pub fn check_sight(&self, origin: Point, target: Point) -> bool {
+        let ray = Ray::new(origin, target);
+        let mut limited = 0;
+
+        self.walls.any(&ray.bounds(), |edge| {
+            if !self.restricts(edge, WallRestrictionType::Sight, origin) {
+                return false;
+            }
+
+            if geometry::line_segment_intersection(&origin, &target, &edge.get_a(), &edge.get_b(), None).is_none() {
+                return false;
+            }
+
+            if edge.sight == WallSenseType::Limited {
+                limited += 1;
+                return limited > 1;
+            }
+
+            true
+        })
+    }
+
+    /// The polygon of what a source at `origin` can reach, the same as Foundry's `ClockwiseSweepPolygon`
+    ///
+    /// A ray is cast just before, at, and just after the angle of each end of the walls that restrict the source, and
+    /// each ray stops at the first wall it crosses or the second limited one. The polygon never leaves the bounds of
+    /// the scene, and without a `radius` it fills them.
+    pub fn compute_visibility_polygon(
+        &self,
+        origin: Point,
+        restriction: WallRestrictionType,
+        radius: Option<f64>,
+    ) -> Vec<Point> {
+        const EPSILON: f64 = 1e-6;
+
+        let reach = radius.unwrap_or_else(|| {
+            [
+                Point::new(self.bounds.left(), self.bounds.top()),
+                Point::new(self.bounds.right(), self.bounds.top()),
+                Point::new(self.bounds.right(), self.bounds.bottom()),
+                Point::new(self.bounds.left(), self.bounds.bottom()),
+            ]
+            .into_iter()
+            .map(|corner| f64::hypot(corner.x - origin.x, corner.y - origin.y))
+            .fold(0.0, f64::max)
+        });
+        if reach <= 0.0 || !self.bounds.contains_point(origin) {
+            return Vec::new();
+        }
+
+        let area = Rectangle::new(origin.x - reach, origin.y - reach, reach * 2.0, reach * 2.0);
+        let mut angles = Vec::new();
+        self.walls.any(&area, |edge| {
+            if self.restricts(edge, restriction, origin) {
+                for point in [edge.get_a(), edge.get_b()] {
+                    let angle = f64::atan2(point.y - origin.y, point.x - origin.x);
+                    angles.extend([angle - EPSILON, angle, angle + EPSILON]);
+                }
+            }
+
+            false
+        });
+
+        match radius {
+            Some(radius) => {
+                let vertices = geometry::approximate_vertex_density(radius, None).max(8);
+                angles.extend((0..vertices).map(|i| (i as f64 / vertices as f64) * TAU));
+            }
+            None => angles.extend(
+                [
+                    Point::new(self.bounds.left(), self.bounds.top()),
+                    Point::new(self.bounds.right(), self.bounds.top()),
+                    Point::new(self.bounds.right(), self.bounds.bottom()),
+                    Point::new(self.bounds.left(), self.bounds.bottom()),
+                ]
+                .map(|corner| f64::atan2(corner.y - origin.y, corner.x - origin.x)),
+            ),
+        }
+
+        let mut angles: Vec<f64> = angles.into_iter().map(|angle| angle.rem_euclid(TAU)).collect();
+        angles.sort_by(f64::total_cmp);
+        angles.dedup_by(|a, b| (*a - *b).abs() < EPSILON / 2.0);
+
+        let mut polygon: Vec<Point> =
+            angles.into_iter().map(|angle| self.cast_ray(origin, angle, reach, restriction)).collect();
+        polygon.dedup_by(|a, b| a.close(*b));
+        if polygon.len() > 1 && polygon[0].close(polygon[polygon.len() - 1]) {
+            polygon.pop();
+        }
+
+        polygon
+    }
+
+    /// Where a ray from `origin` at `angle` stops, see [`Walls::compute_visibility_polygon`]
+    fn cast_ray(&self, origin: Point, angle: f64, reach: f64, restriction: WallRestrictionType) -> Point {
+        let direction = Point::new(angle.cos(), angle.sin());
+        let end = origin + (direction * reach);
+
+        let mut hits = Vec::new();
+        self.walls.any(&Ray::new(origin, end).bounds(), |edge| {
+            if self.restricts(edge, restriction, origin) {
+                if let Some(intersection) =
+                    geometry::line_segment_intersection(&origin, &end, &edge.get_a(), &edge.get_b(), None)
+                {
+                    hits.push((intersection.t0, edge.get_restriction(restriction) == WallSenseType::Limited));
+                }
+            }
+
+            false
+        });
+        hits.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut t = f64::min(self.get_exit(origin, direction) / reach, 1.0);
+        let mut limited = false;
+        for (hit, is_limited) in hits {
+            if hit >= t {
+                break;
+            }
+
+            if is_limited && !limited {
+                limited = true;
+                continue;
+            }
+
+            t = hit;
+            break;
+        }
+
+        origin + (direction * (reach * t))
+    }
+
+    /// How far a ray from `origin`, inside the bounds of the scene, travels in `direction` before leaving them
+    fn get_exit(&self, origin: Point, direction: Point) -> f64 {
+        let distance = |position: f64, direction: f64, min: f64, max: f64| {
+            if direction > 0.0 {
+                (max - position) / direction
+            } else if direction < 0.0 {
+                (min - position) / direction
+            } else {
+                f64::INFINITY
+            }
+        };
+
+        f64::min(
+            distance(origin.x, direction.x, self.bounds.left(), self.bounds.right()),
+            distance(origin.y, direction.y, self.bounds.top(), self.bounds.bottom()),
+        )
+    }
+
+    /// Whether a wall restricts a source at `origin`, regardless of where the source is going
+    fn restricts(&self, wall: &Wall, restriction: WallRestrictionType, origin: Point) -> bool {
+        wall.get_restriction(restriction) != WallSenseType::None
+            && wall.blocks_direction(&origin)
+            && !wall.applies_threshold(restriction, origin, self.grid.size() / self.grid.distance())
+    }
+
+    pub fn check_collisions(&self, offsets: Vec<(ElevatedPoint, ElevatedPoint)>, profile: &MovementProfile) -> bool {
+        for (start, end) in offsets {
+            if self.check_collision(start, end, profile) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    pub fn get_corners(&self, radius: f64, profile: &MovementProfile) -> Vec<Point> {
+        let mut corners = Vec::new();
+
+        for wall in self.walls.iter() {
+            if !wall.blocks_profile(profile) {
+                continue;
+            }
+
+            let a = wall.get_a();
+            let b = wall.get_b();
+            let length = f64::hypot(b.x - a.x, b.y - a.y);
+            if length == 0.0 {
+                continue;
+            }
+
+            let direction = (b - a) / length;
+            let normal = Point::new(-direction.y, direction.x);
+
+            for (point, direction) in [(a, direction * -1.0), (b, direction)] {
+                corners.push(point + ((direction + normal) * radius));
+                corners.push(point + ((direction - normal) * radius));
+            }
+        }
+
+        corners
+    }
+
+    pub fn check_point(&self, point: ElevatedPoint, profile: &MovementProfile) -> bool {
+        let rect = Rectangle { x: point.x, y: point.y, width: 0.0, height: 0.0 };
+
+        self.walls.any(&rect, |edge| {
+            if edge.blocks_profile(profile) && edge.contains_elevation(point.elevation) {
+                if let Some(closest_point) =
+                    geometry::closest_point_to_segment(&point.into(), &edge.get_a(), &edge.get_b())
+                {
+                    if Point::from(point).close(closest_point) {
+                        return true;
+                    }
+                }
+            }
+
+            false
+        })
+    }
+
+    /// Whether a wall that always blocks is crossed moving from `start` to `end`, the same as [`Walls::check_collision`]
+    fn crosses(wall: &Wall, start: Point, end: Point) -> bool {
+        wall.blocks_direction(&start)
+            && geometry::line_segment_intersection(&start, &end, &wall.get_a(), &wall.get_b(), None).is_some()
+    }
+
+    fn get_center_point(&self, offset: GridOffset2D) -> Point {
+        self.grid.get_center_point(offset.into()).into()
+    }
+
+    /// The bit used for moving from one grid space to another, staying in the same space (changing elevation) is
+    /// included since a wall could be touching the center of the space
+    fn get_direction(start: GridOffset2D, end: GridOffset2D) -> Option<u16> {
+        let di = end.i - start.i;
+        let dj = end.j - start.j;
+
+        if di.abs() > 1 || dj.abs() > 1 {
+            return None;
+        }
+
+        Some(((di + 1) * 3 + (dj + 1)) as u16)
+    }
+
+    fn get_neighbors(offset: GridOffset2D) -> impl Iterator<Item = (u16, GridOffset2D)> {
+        (-1..=1).flat_map(move |di| {
+            (-1..=1).map(move |dj| {
+                let neighbor = GridOffset2D { i: offset.i + di, j: offset.j + dj };
+                (Walls::get_direction(offset, neighbor).unwrap(), neighbor)
+            })
+        })
+    }
+
+    /// The grid spaces where moving to a neighbor could cross something inside of `rect`
+    fn get_nearby_offsets(&self, rect: &Rectangle) -> Vec<GridOffset2D> {
+        let get_offset = |x: f64, y: f64| -> GridOffset2D {
+            match &self.grid {
+                Grid::Gridless(_) => GridOffset2D { i: 0, j: 0 },
+                Grid::Square(square_grid) => GridOffset2D {
+                    i: (y / square_grid.size as f64).floor() as i32,
+                    j: (x / square_grid.size as f64).floor() as i32,
+                },
+                Grid::Hexagonal(hexagonal_grid) => {
+                    hexagonal_grid.cube_to_offset(hexagonal_grid.point_to_cube(ElevatedPoint::new(x, y, 0.0))).into()
+                }
+            }
+        };
+
+        // Neighboring spaces are at most one space apart
+        if let Grid::Gridless(_) = self.grid {
+            return Vec::new();
+        }
+
+        let margin = f64::max(self.grid.size_x(), self.grid.size_y());
+        let corners = [
+            get_offset(rect.left() - margin, rect.top() - margin),
+            get_offset(rect.right() + margin, rect.top() - margin),
+            get_offset(rect.left() - margin, rect.bottom() + margin),
+            get_offset(rect.right() + margin, rect.bottom() + margin),
+        ];
+
+        let min_i = corners.iter().map(|offset| offset.i).min().unwrap() - 1;
+        let max_i = corners.iter().map(|offset| offset.i).max().unwrap() + 1;
+        let min_j = corners.iter().map(|offset| offset.j).min().unwrap() - 1;
+        let max_j = corners.iter().map(|offset| offset.j).max().unwrap() + 1;
+
+        (min_i..=max_i).flat_map(|i| (min_j..=max_j).map(move |j| GridOffset2D { i, j })).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        enums::{GridDiagonals, WallDirection, WallDoorState, WallDoorType, WallMovementType, WallSenseType},
+        grids::{HexagonalGrid, SquareGrid},
+        types::{WallFlags, WallHeight, WallThreshold},
+    };
+    use std::f64::consts::PI;
+
+    fn wall(id: usize, c: [f64; 4], dir: WallDirection, height: WallHeight) -> Wall {
+        Wall {
+            id: id.to_string(),
+            c,
+            light: WallSenseType::Normal,
+            r#move: WallMovementType::Normal,
+            sight: WallSenseType::Normal,
+            sound: WallSenseType::Normal,
+            dir,
+            door: WallDoorType::None,
+            ds: WallDoorState::Closed,
+            flags: WallFlags { burrow: id.is_multiple_of(7) },
+            height,
+            threshold: WallThreshold::default(),
+        }
+    }
+
+    /// Walls snapped to half a grid space, so plenty of them touch the centers and corners of spaces
+    fn random_walls() -> Vec<Wall> {
+        let mut seed: u64 = 0x9E3779B97F4A7C15;
+        let mut random = |max: u64| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed % max
+        };
+
+        (0..200_usize)
+            .map(|id| {
+                let x = random(20) as f64 * 50.0;
+                let y = random(20) as f64 * 50.0;
+                let c = [x, y, x + (random(7) as f64 - 3.0) * 50.0, y + (random(7) as f64 - 3.0) * 50.0];
+                let dir = [WallDirection::Both, WallDirection::Left, WallDirection::Right][random(3) as usize];
+                let height = if id.is_multiple_of(5) {
+                    WallHeight { bottom: 0.0, top: 10.0 }
+                } else {
+                    WallHeight { bottom: f64::NEG_INFINITY, top: f64::INFINITY }
+                };
+
+                wall(id, c, dir, height)
+            })
+            .collect()
+    }
+
+    fn assert_moves_match_collisions(walls: &Walls) {
+        for profile in [MovementProfile::new("walk"), MovementProfile::new("burrow")] {
+            for i in -2..12 {
+                for j in -2..12 {
+                    for k in [0, 3] {
+                        let start = GridOffset3D { i, j, k };
+                        for (_, neighbor) in Walls::get_neighbors(start.into()) {
+                            let end = GridOffset3D { i: neighbor.i, j: neighbor.j, k };
+                            let get_point = |offset: GridOffset3D| ElevatedPoint {
+                                elevation: walls.grid.get_top_left_point(offset).elevation,
+                                ..walls.grid.get_center_point(offset)
+                            };
+
+                            assert_eq!(
+                                walls.check_move(start, end, &profile),
+                                walls.check_collision(get_point(start), get_point(end), &profile),
+                                "{start:?} to {end:?} with {}",
+                                profile.action
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn assert_cache_matches_collisions(grid: Grid) {
+        let mut walls = Walls::new(Rectangle::new(0.0, 0.0, 1000.0, 1000.0), &grid, random_walls());
+        assert!(!walls.blocked.is_empty());
+        assert_moves_match_collisions(&walls);
+
+        for id in (0..200).step_by(3) {
+            walls.delete_wall(&id.to_string());
+        }
+        for wall in random_walls().into_iter().skip(1).step_by(3) {
+            walls.update_wall(Wall { dir: WallDirection::Both, ..wall });
+        }
+        assert_moves_match_collisions(&walls);
+
+        for id in 0..200 {
+            walls.delete_wall(&id.to_string());
+        }
+        assert!(walls.blocked.is_empty());
+    }
+
+    #[test]
+    fn square_cache_matches_collisions() {
+        assert_cache_matches_collisions(Grid::Square(SquareGrid {
+            size: 100,
+            distance: 5.0,
+            diagonals: GridDiagonals::Equidistant,
+        }));
+    }
+
+    #[test]
+    fn hexagonal_cache_matches_collisions() {
+        for columns in [false, true] {
+            let size = 100.0;
+            let long = size * 2.0 / 3.0_f64.sqrt();
+
+            assert_cache_matches_collisions(Grid::Hexagonal(HexagonalGrid {
+                size: 100,
+                distance: 5.0,
+                size_x: if columns { long } else { size },
+                size_y: if columns { size } else { long },
+                columns,
+                even: columns,
+            }));
+        }
+    }
+
+    #[test]
+    fn clearance_is_only_checked_against_walls_that_always_block() {
+        let grid = Grid::Square(SquareGrid { size: 100, distance: 5.0, diagonals: GridDiagonals::Equidistant });
+        let height = || WallHeight { bottom: f64::NEG_INFINITY, top: f64::INFINITY };
+        let check = |wall: Wall, point: Point| {
+            Walls::new(Rectangle::new(0.0, 0.0, 1000.0, 1000.0), &grid, vec![wall]).check_clearance(
+                point,
+                50.0,
+                &MovementProfile::new("walk"),
+            )
+        };
+        let divider = || wall(1, [500.0, 0.0, 500.0, 1000.0], WallDirection::Both, height());
+
+        assert!(check(divider(), Point::new(460.0, 500.0)));
+        assert!(!check(divider(), Point::new(440.0, 500.0)));
+        assert!(!check(Wall { dir: WallDirection::Left, ..divider() }, Point::new(460.0, 500.0)));
+        assert!(!check(Wall { height: WallHeight { bottom: 0.0, top: 10.0 }, ..divider() }, Point::new(460.0, 500.0)));
+    }
+
+    #[test]
+    fn sight_is_blocked_by_walls_that_block_vision() {
+        let grid = Grid::Square(SquareGrid { size: 100, distance: 5.0, diagonals: GridDiagonals::Equidistant });
+        let height = || WallHeight { bottom: f64::NEG_INFINITY, top: f64::INFINITY };
+        let sight = |id, x: f64, sight, door, ds| Wall {
+            sight,
+            door,
+            ds,
+            ..wall(id, [x, 0.0, x, 1000.0], WallDirection::Both, height())
+        };
+        let check = |walls: Vec<Wall>| {
+            Walls::new(Rectangle::new(0.0, 0.0, 1000.0, 1000.0), &grid, walls)
+                .check_sight(Point::new(50.0, 500.0), Point::new(950.0, 500.0))
+        };
+
+        assert!(!check(vec![]));
+        assert!(check(vec![sight(1, 500.0, WallSenseType::Normal, WallDoorType::None, WallDoorState::Closed)]));
+        assert!(!check(vec![sight(1, 500.0, WallSenseType::None, WallDoorType::None, WallDoorState::Closed)]));
+        assert!(check(vec![sight(1, 500.0, WallSenseType::Normal, WallDoorType::Door, WallDoorState::Closed)]));
+        assert!(!check(vec![sight(1, 500.0, WallSenseType::Normal, WallDoorType::Door, WallDoorState::Open)]));
+
+        // A limited wall only blocks sight behind a second one
+        assert!(!check(vec![sight(1, 300.0, WallSenseType::Limited, WallDoorType::None, WallDoorState::Closed)]));
+        assert!(check(vec![
+            sight(1, 300.0, WallSenseType::Limited, WallDoorType::None, WallDoorState::Closed),
+            sight(2, 600.0, WallSenseType::Limited, WallDoorType::None, WallDoorState::Closed),
+        ]));
+
+        // A one way wall only blocks sight from one side
+        let walls = Walls::new(
+            Rectangle::new(0.0, 0.0, 1000.0, 1000.0),
+            &grid,
+            vec![wall(1, [500.0, 0.0, 500.0, 1000.0], WallDirection::Left, height())],
+        );
+        assert_ne!(
+            walls.check_sight(Point::new(50.0, 500.0), Point::new(950.0, 500.0)),
+            walls.check_sight(Point::new(950.0, 500.0), Point::new(50.0, 500.0))
+        );
+    }
+
+    #[test]
+    fn visibility_polygons_stop_at_restricting_walls() {
+        let grid = Grid::Square(SquareGrid { size: 100, distance: 5.0, diagonals: GridDiagonals::Equidistant });
+        let height = || WallHeight { bottom: f64::NEG_INFINITY, top: f64::INFINITY };
+        let divider = |id, x: f64, sight, threshold| Wall {
+            sight,
+            threshold: WallThreshold { sight: threshold, ..Default::default() },
+            ..wall(id, [x, 0.0, x, 1000.0], WallDirection::Both, height())
+        };
+        let area = |walls: Vec<Wall>, restriction, radius| {
+            let walls = Walls::new(Rectangle::new(0.0, 0.0, 1000.0, 1000.0), &grid, walls);
+            let polygon = walls.compute_visibility_polygon(Point::new(250.0, 500.0), restriction, radius);
+            geometry::polygon_signed_area(&polygon).abs()
+        };
+        let close = |a: f64, b: f64| (a - b).abs() < b * 0.02;
+
+        assert!(close(area(vec![], WallRestrictionType::Sight, None), 1000.0 * 1000.0));
+        assert!(close(area(vec![], WallRestrictionType::Sight, Some(100.0)), PI * 100.0 * 100.0));
+
+        let normal = || vec![divider(1, 500.0, WallSenseType::Normal, None)];
+        assert!(close(area(normal(), WallRestrictionType::Sight, None), 500.0 * 1000.0));
+        assert!(close(area(normal(), WallRestrictionType::Sight, Some(100.0)), PI * 100.0 * 100.0));
+
+        // A window doesn't block sight, but still blocks movement
+        let window = || vec![divider(1, 500.0, WallSenseType::None, None)];
+        assert!(close(area(window(), WallRestrictionType::Sight, None), 1000.0 * 1000.0));
+        assert!(close(area(window(), WallRestrictionType::Move, None), 500.0 * 1000.0));
+
+        // Sight passes through the first limited wall and stops at the second
+        let limited = |x| divider(1, x, WallSenseType::Limited, None);
+        assert!(close(area(vec![limited(500.0)], WallRestrictionType::Sight, None), 1000.0 * 1000.0));
+        assert!(close(
+            area(
+                vec![limited(500.0), divider(2, 750.0, WallSenseType::Limited, None)],
+                WallRestrictionType::Sight,
+                None
+            ),
+            750.0 * 1000.0
+        ));
+
+        // The origin is 250 pixels from the wall, a threshold of 10 is 200 pixels and a threshold of 15 is 300 pixels
+        let threshold = |sight, threshold| vec![divider(1, 500.0, sight, Some(threshold))];
+        assert!(close(
+            area(threshold(WallSenseType::Proximity, 10.0), WallRestrictionType::Sight, None),
+            500.0 * 1000.0
+        ));
+        assert!(close(
+            area(threshold(WallSenseType::Proximity, 15.0), WallRestrictionType::Sight, None),
+            1000.0 * 1000.0
+        ));
+        assert!(close(
+            area(threshold(WallSenseType::Distance, 10.0), WallRestrictionType::Sight, None),
+            1000.0 * 1000.0
+        ));
+        assert!(close(
+            area(threshold(WallSenseType::Distance, 15.0), WallRestrictionType::Sight, None),
+            500.0 * 1000.0
+        ));
+    }
+}
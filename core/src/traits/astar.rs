@@ -0,0 +1,227 @@
+use crate::{
+    modules::search::{Search, SearchResult},
+    traits::{node::COST_SCALE, BaseGrid, Node, SkipLast, TokenShape},
+    types::{
+        ElevatedPoint, ElevationWindow, FindMovementPathOptions, FindMovementPathResult, FindMovementPathSegment,
+        GridMeasurePathResult, MovementProfile, PathSearch, SearchContext, TokenDocument, TokenMovementWaypoint,
+    },
+};
+use std::{collections::HashMap, ops::RangeInclusive};
+
+/// How many neighbors an expansion is expected to check, expansions that check more use up more of the budget
+const NEIGHBORS_PER_EXPANSION: usize = 8;
+
+pub trait AStar<N: Node + Eq, T: TokenShape>: BaseGrid<N, T> {
+    fn find_path(
+        &self,
+        waypoints: Vec<TokenMovementWaypoint>,
+        token: &TokenDocument,
+        context: &SearchContext,
+        options: &FindMovementPathOptions,
+        grid_measure_path_result: &GridMeasurePathResult,
+    ) -> FindMovementPathResult {
+        let mut search = self.start_path(waypoints, token, options, grid_measure_path_result);
+        let mut budget = usize::MAX;
+        self.step_path(&mut search, context, &mut budget).unwrap()
+    }
+
+    fn start_path(
+        &self,
+        waypoints: Vec<TokenMovementWaypoint>,
+        token: &TokenDocument,
+        options: &FindMovementPathOptions,
+        grid_measure_path_result: &GridMeasurePathResult,
+    ) -> PathSearch<N, T> {
+        let token_shape = self.get_token_shape(token.width, token.height, token.shape);
+        let mut start_node = match waypoints.first() {
+            Some(waypoint) => self.get_node(waypoint.create_elevated_point(), &token_shape),
+            None => self.get_node(token.create_waypoint().create_elevated_point(), &token_shape),
+        };
+
+        start_node.set_diagonals(grid_measure_path_result.diagonals);
+
+        PathSearch {
+            path: waypoints.iter().take(1).cloned().collect(),
+            waypoints,
+            index: 1,
+            start_node,
+            token_shape,
+            search: None,
+            vertices: Vec::new(),
+            segments: Vec::new(),
+            unreachable: None,
+            closest: options.closest,
+            elevation_window: options.elevation_window,
+            vertical_cost: options.vertical_cost,
+            expanded: 0,
+            max_nodes: options.max_nodes.map(|max_nodes| max_nodes as usize),
+            truncated: false,
+        }
+    }
+
+    /// The elevations, as node elevations, a search between two nodes may move through
+    fn get_elevation_range(
+        &self,
+        start_node: &N,
+        end_node: &N,
+        token_shape: &T,
+        elevation_window: Option<ElevationWindow>,
+    ) -> RangeInclusive<i32> {
+        let low = i32::min(start_node.get_elevation(), end_node.get_elevation());
+        let high = i32::max(start_node.get_elevation(), end_node.get_elevation());
+        let get_elevation =
+            |elevation: f64| self.get_node(ElevatedPoint { x: 0.0, y: 0.0, elevation }, token_shape).get_elevation();
+
+        match elevation_window {
+            None => low..=high,
+            Some(ElevationWindow::Levels(levels)) => low.saturating_sub(levels)..=high.saturating_add(levels),
+            Some(ElevationWindow::Range { min, max }) => {
+                i32::min(low, get_elevation(min))..=i32::max(high, get_elevation(max))
+            }
+        }
+    }
+
+    /// Continues a path search for up to `budget` node expansions, returning the result once it's finished
+    ///
+    /// Expansions that check more than a few neighbors, like the ones on gridless grids, count for more than one. If
+    /// the search has been truncated, or runs out of nodes, the path will end at the node closest to the current
+    /// waypoint.
+    fn step_path(
+        &self,
+        search: &mut PathSearch<N, T>,
+        context: &SearchContext,
+        budget: &mut usize,
+    ) -> Option<FindMovementPathResult> {
+        while search.unreachable.is_none() && search.index < search.waypoints.len() {
+            let index = search.index;
+            let start_waypoint = &search.waypoints[index - 1];
+            let end_waypoint = &search.waypoints[index];
+            let start_node = search.start_node;
+            let end_node = self.get_node(end_waypoint.create_elevated_point(), &search.token_shape);
+
+            let profile = MovementProfile::new(&end_waypoint.action).with_vertical_cost(search.vertical_cost);
+
+            if search.search.is_none() {
+                if self.is_occupied(&end_node, &search.token_shape, context.occupancy) {
+                    search.unreachable = Some(index);
+                    break;
+                }
+
+                search.vertices = self.get_vertices(&search.token_shape, &profile, context);
+                search.search = Some(Search::new(&start_node, self.get_heuristic(&start_node, &end_node)));
+            }
+
+            let elevation_range =
+                self.get_elevation_range(&start_node, &end_node, &search.token_shape, search.elevation_window);
+            let context = SearchContext { vertices: &search.vertices, ..*context };
+            let get_adjacent_nodes = |node: &N| {
+                self.get_adjacent_nodes(node, &end_node, &search.token_shape, &elevation_range, &profile, &context)
+            };
+
+            let remaining = match search.max_nodes {
+                _ if search.truncated => 0,
+                Some(max_nodes) => max_nodes.saturating_sub(search.expanded),
+                None => usize::MAX,
+            };
+            // Gridless nodes check every vertex as a neighbor, so each expansion counts for as many grid expansions
+            let weight = usize::max(1, search.vertices.len() / NEIGHBORS_PER_EXPANSION);
+            let mut leg_budget = usize::min(budget.div_ceil(weight), remaining);
+            let available = leg_budget;
+
+            let result = search.search.as_mut().unwrap().step(
+                &mut leg_budget,
+                get_adjacent_nodes,
+                |node| self.get_heuristic(node, &end_node),
+                |node| node.at_node(&end_node),
+            );
+
+            *budget = budget.saturating_sub((available - leg_budget).saturating_mul(weight));
+            search.expanded += available - leg_budget;
+            if search.max_nodes.is_some_and(|max_nodes| search.expanded >= max_nodes) {
+                search.truncated = true;
+            }
+
+            let path = match result {
+                Some(SearchResult::Found(path)) => path,
+                Some(SearchResult::NotFound(path)) => {
+                    search.unreachable = Some(index);
+
+                    if !search.closest || path.len() <= 1 {
+                        break;
+                    }
+
+                    path
+                }
+                None if search.truncated => {
+                    let path = search.search.as_ref().unwrap().closest_path();
+
+                    if path.len() <= 1 {
+                        break;
+                    }
+
+                    path
+                }
+                None => return None,
+            };
+            let reached = path.last().unwrap().0.at_node(&end_node);
+
+            // Total cost and distance (in scaled grid spaces) from the start of the leg to each node
+            let mut totals = HashMap::new();
+            let mut total_distance = 0;
+            for (i, (node, total_cost)) in path.iter().enumerate() {
+                if i > 0 {
+                    total_distance += self.get_heuristic(&path[i - 1].0, node);
+                }
+                totals.insert(*node, (*total_cost, total_distance));
+            }
+
+            let nodes = self.simplify_path(path.into_iter().map(|(node, _cost)| node).collect());
+
+            for node in nodes.iter().skip(1).skip_last() {
+                search.path.push(start_waypoint.from_elevated_point(
+                    self.get_node_top_left_point(node).round(),
+                    true,
+                    false,
+                    true,
+                ));
+            }
+
+            if !reached {
+                search.path.push(start_waypoint.from_elevated_point(
+                    self.get_node_top_left_point(nodes.last().unwrap()).round(),
+                    true,
+                    false,
+                    true,
+                ));
+            } else {
+                search.path.push(end_waypoint.clone());
+            }
+
+            let mut previous = (0, 0);
+            for node in nodes.iter().skip(if nodes.len() > 1 { 1 } else { 0 }) {
+                let (total_cost, total_distance) = totals[node];
+                search.segments.push(FindMovementPathSegment {
+                    cost: (total_cost - previous.0) as f64 / COST_SCALE as f64,
+                    distance: (total_distance - previous.1) as f64 / COST_SCALE as f64,
+                });
+                previous = (total_cost, total_distance);
+            }
+
+            if !reached {
+                break;
+            }
+
+            search.token_shape = self.get_token_shape(end_waypoint.width, end_waypoint.height, end_waypoint.shape);
+            search.start_node = *nodes.last().unwrap();
+            search.search = None;
+            search.index += 1;
+        }
+
+        Some(FindMovementPathResult::new(
+            std::mem::take(&mut search.path),
+            std::mem::take(&mut search.segments),
+            search.unreachable,
+            search.truncated,
+        ))
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{
+    enums::TokenShapeType,
+    scene::{Regions, Tokens},
+    traits::{Node, TokenShape},
+    types::{ElevatedPoint, GridOffset3D, MovementProfile, Occupancy, Point, Rectangle, SearchContext, TokenDocument},
+};
+use std::ops::RangeInclusive;
+
+pub trait BaseGrid<N: Node, T: TokenShape> {
+    fn convert_node_to_offset(&self, node: N) -> GridOffset3D;
+    fn convert_offset_to_node(&self, offset: GridOffset3D) -> N;
+    fn get_adjacent_nodes(
+        &self,
+        node: &N,
+        end_node: &N,
+        token_shape: &T,
+        elevation_range: &RangeInclusive<i32>,
+        profile: &MovementProfile,
+        SearchContext { bounds, walls, regions, fog, exploration_coverage, occupancy, sneak, .. }: &SearchContext,
+    ) -> Vec<(N, u32)> {
+        node.get_neighbors()
+            .into_iter()
+            .filter(|(neighbor, _cost)| elevation_range.contains(&neighbor.get_elevation()))
+            .filter(|(neighbor, _cost)| {
+                let climb = neighbor.get_elevation() - node.get_elevation();
+                profile.free_elevation
+                    || climb == 0
+                    || climb.signum() == (end_node.get_elevation() - node.get_elevation()).signum()
+            })
+            .filter(|(neighbor, _cost)| bounds.contains_point(self.get_node_center_point(neighbor).into()))
+            .filter(|(neighbor, _cost)| match (fog, exploration_coverage) {
+                (None, _) => true,
+                (Some(fog), None) => fog.is_point_explored(self.get_node_center_point(neighbor).into()),
+                (Some(fog), Some(coverage)) => self
+                    .get_occupied_grid_space_offsets(self.convert_node_to_offset(*neighbor), token_shape)
+                    .into_iter()
+                    .all(|offset| {
+                        let explored = fog.get_explored_coverage(&self.get_offset_bounds(offset));
+                        explored > 0.0 && explored >= *coverage
+                    }),
+            })
+            .filter(|(neighbor, _cost)| {
+                !occupancy.is_blocked(
+                    &self.get_occupied_grid_space_offsets(self.convert_node_to_offset(*neighbor), token_shape),
+                )
+            })
+            .filter(|(neighbor, _cost)| {
+                let large = token_shape.get_width() > 1.0 || token_shape.get_height() > 1.0;
+                let center = self.get_token_center_point(self.get_node_top_left_point(neighbor), token_shape);
+                if large && walls.check_point(center, profile) {
+                    return false;
+                }
+
+                // Walls are checked at the elevation of the token, which is the bottom of the space
+                !self
+                    .get_occupied_grid_space_offsets(self.convert_node_to_offset(*node), token_shape)
+                    .into_iter()
+                    .zip(self.get_occupied_grid_space_offsets(self.convert_node_to_offset(*neighbor), token_shape))
+                    .any(|(start, end)| walls.check_move(start, end, profile))
+            })
+            .filter_map(|(neighbor, cost)| {
+                let mut multiplier = self.get_cost_multiplier(&neighbor, token_shape, profile, regions);
+                if neighbor.get_elevation() != node.get_elevation() {
+                    multiplier *= profile.vertical_cost;
+                }
+                if let Some(sneak) = sneak {
+                    let points = self
+                        .get_occupied_grid_space_offsets(self.convert_node_to_offset(neighbor), token_shape)
+                        .into_iter()
+                        .map(|offset| self.get_offset_center_point(offset).into());
+                    multiplier *= sneak.get_cost_multiplier(points, walls);
+                }
+
+                if multiplier.is_finite() {
+                    Some((neighbor, (cost as f64 * multiplier).round() as u32))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+    /// Points the token could move to besides the neighbors of each node, found once at the start of each leg of a
+    /// search, see [`SearchContext::vertices`]
+    fn get_vertices(&self, _token_shape: &T, _profile: &MovementProfile, _context: &SearchContext) -> Vec<Point> {
+        Vec::new()
+    }
+    fn get_cost_multiplier(&self, node: &N, token_shape: &T, profile: &MovementProfile, regions: &Regions) -> f64 {
+        self.get_occupied_grid_space_offsets(self.convert_node_to_offset(*node), token_shape)
+            .into_iter()
+            .map(|offset| {
+                regions.get_offset_cost_multiplier(
+                    offset,
+                    ElevatedPoint {
+                        elevation: self.get_offset_top_left_point(offset).elevation,
+                        ..self.get_offset_center_point(offset)
+                    },
+                    profile,
+                )
+            })
+            .reduce(f64::max)
+            .unwrap_or(1.0)
+    }
+    fn get_heuristic(&self, node: &N, end_node: &N) -> u32 {
+        node.get_distance(end_node)
+    }
+    fn get_occupancy(&self, token: &TokenDocument, tokens: &Tokens) -> Occupancy {
+        let mut occupancy = Occupancy::default();
+
+        for other in tokens.get_obstacles(token) {
+            let token_shape = self.get_token_shape(other.width, other.height, other.shape);
+            let offset = self.get_offset(other.create_waypoint().create_elevated_point(), &token_shape);
+            let offsets = self.get_occupied_grid_space_offsets(offset, &token_shape);
+
+            // Tokens that aren't hostile can be moved through, but a path can't end on them
+            if other.disposition.is_hostile_to(&token.disposition) {
+                occupancy.hostile.extend(offsets);
+            } else {
+                occupancy.allied.extend(offsets);
+            }
+        }
+
+        occupancy
+    }
+    fn is_occupied(&self, node: &N, token_shape: &T, occupancy: &Occupancy) -> bool {
+        occupancy.is_occupied(&self.get_occupied_grid_space_offsets(self.convert_node_to_offset(*node), token_shape))
+    }
+    fn get_node(&self, point: ElevatedPoint, token_shape: &T) -> N;
+    fn get_node_center_point(&self, node: &N) -> ElevatedPoint;
+    fn get_node_top_left_point(&self, node: &N) -> ElevatedPoint;
+    fn get_occupied_grid_space_offsets(&self, offset: GridOffset3D, token_shape: &T) -> Vec<GridOffset3D>;
+    fn get_offset(&self, point: ElevatedPoint, token_shape: &T) -> GridOffset3D;
+    fn get_offset_center_point(&self, offset: GridOffset3D) -> ElevatedPoint;
+    fn get_offset_top_left_point(&self, offset: GridOffset3D) -> ElevatedPoint;
+    /// The bounding box of a grid space
+    fn get_offset_bounds(&self, offset: GridOffset3D) -> Rectangle {
+        let top_left = self.get_offset_top_left_point(offset);
+        let center = self.get_offset_center_point(offset);
+        Rectangle::new(top_left.x, top_left.y, (center.x - top_left.x) * 2.0, (center.y - top_left.y) * 2.0)
+    }
+    fn get_token_center_point(&self, point: ElevatedPoint, token_shape: &T) -> ElevatedPoint;
+    fn get_token_shape(&self, width: f64, height: f64, shape: TokenShapeType) -> T;
+    fn simplify_path(&self, path: Vec<N>) -> Vec<N>;
+}
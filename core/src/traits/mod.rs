@@ -0,0 +1,11 @@
+pub mod astar;
+pub mod base_grid;
+pub mod node;
+pub mod skip_last;
+pub mod token_shape;
+
+pub use self::astar::AStar;
+pub use self::base_grid::BaseGrid;
+pub use self::node::Node;
+pub use self::skip_last::SkipLast;
+pub use self::token_shape::TokenShape;
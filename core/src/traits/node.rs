@@ -1,6 +1,8 @@
 use std::fmt::Debug;
 use std::hash::Hash;
 
+pub const COST_SCALE: u32 = 1000;
+
 pub trait Node
 where Self: Clone + Copy + Debug + Hash + Sized
 {
@@ -8,5 +10,5 @@ where Self: Clone + Copy + Debug + Hash + Sized
     fn get_distance(&self, other: &Self) -> u32;
     fn get_elevation(&self) -> i32;
     fn get_neighbors(&self) -> Vec<(Self, u32)>;
-    fn set_diagonal(&mut self, diagonal: bool);
+    fn set_diagonals(&mut self, diagonals: i32);
 }
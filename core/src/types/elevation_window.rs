@@ -0,0 +1,9 @@
+/// How far a search may move above or below the elevations of the waypoints it's searching between
+#[derive(Clone, Copy)]
+#[derive(Debug)]
+pub enum ElevationWindow {
+    /// A number of grid spaces above and below
+    Levels(i32),
+    /// A range of elevations, in scene units
+    Range { min: f64, max: f64 },
+}
@@ -0,0 +1,19 @@
+use crate::types::{ElevationWindow, SneakOptions};
+
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(Default)]
+pub struct FindMovementPathOptions {
+    pub use_exploration: bool,
+    /// Check every space the token covers, a space is explored once at least this fraction of it is
+    pub exploration_coverage: Option<f64>,
+    pub ignore_tokens: bool,
+    pub closest: bool,
+    pub max_nodes: Option<u32>,
+    pub timeout: Option<f64>,
+    pub elevation_window: Option<ElevationWindow>,
+    /// Multiplies the cost of changing elevation when walking, see
+    /// [`MovementProfile::with_vertical_cost`](crate::types::MovementProfile::with_vertical_cost)
+    pub vertical_cost: Option<f64>,
+    pub sneak: Option<SneakOptions>,
+}
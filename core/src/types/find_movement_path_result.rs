@@ -0,0 +1,54 @@
+use crate::types::TokenMovementWaypoint;
+
+#[derive(Clone, Copy)]
+#[derive(Debug)]
+#[derive(Default)]
+pub struct FindMovementPathSegment {
+    pub cost: f64,
+    pub distance: f64,
+}
+
+#[derive(Clone)]
+#[derive(Debug)]
+pub struct FindMovementPathResult {
+    pub waypoints: Vec<TokenMovementWaypoint>,
+    pub segments: Vec<FindMovementPathSegment>,
+    pub cost: f64,
+    pub distance: f64,
+    pub unreachable: Option<usize>,
+    pub truncated: bool,
+}
+
+impl FindMovementPathResult {
+    pub fn new(
+        waypoints: Vec<TokenMovementWaypoint>,
+        segments: Vec<FindMovementPathSegment>,
+        unreachable: Option<usize>,
+        truncated: bool,
+    ) -> Self {
+        FindMovementPathResult {
+            cost: segments.iter().map(|segment| segment.cost).sum(),
+            distance: segments.iter().map(|segment| segment.distance).sum(),
+            waypoints,
+            segments,
+            unreachable,
+            truncated,
+        }
+    }
+
+    pub fn scale(self, distance: f64) -> Self {
+        FindMovementPathResult {
+            segments: self
+                .segments
+                .iter()
+                .map(|segment| FindMovementPathSegment {
+                    cost: segment.cost * distance,
+                    distance: segment.distance * distance,
+                })
+                .collect(),
+            cost: self.cost * distance,
+            distance: self.distance * distance,
+            ..self
+        }
+    }
+}
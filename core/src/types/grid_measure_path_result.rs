@@ -0,0 +1,9 @@
+#[derive(Clone, Copy)]
+#[derive(Debug)]
+pub struct GridMeasurePathResult {
+    pub distance: f64,
+    pub cost: f64,
+    pub spaces: i32,
+    pub diagonals: i32,
+    pub euclidean: f64,
+}
@@ -0,0 +1,32 @@
+#[derive(Clone, Copy)]
+#[derive(Debug)]
+#[derive(Hash)]
+#[derive(PartialEq, Eq)]
+pub struct HexagonalGridCube2D {
+    pub q: i32,
+    pub r: i32,
+    pub s: i32,
+}
+
+#[derive(Clone, Copy)]
+#[derive(Debug)]
+#[derive(Hash)]
+#[derive(PartialEq, Eq)]
+pub struct HexagonalGridCube3D {
+    pub q: i32,
+    pub r: i32,
+    pub s: i32,
+    pub k: i32,
+}
+
+impl From<HexagonalGridCube3D> for HexagonalGridCube2D {
+    fn from(HexagonalGridCube3D { q, r, s, k: _ }: HexagonalGridCube3D) -> Self {
+        HexagonalGridCube2D { q, r, s }
+    }
+}
+
+impl From<HexagonalGridCube2D> for HexagonalGridCube3D {
+    fn from(HexagonalGridCube2D { q, r, s }: HexagonalGridCube2D) -> Self {
+        HexagonalGridCube3D { q, r, s, k: 0 }
+    }
+}
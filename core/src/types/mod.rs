@@ -0,0 +1,63 @@
+mod elevation_window;
+mod find_movement_path_options;
+mod find_movement_path_result;
+mod grid_measure_path_result;
+mod grid_offset;
+mod hexagonal_grid_cube;
+mod line_intersection;
+mod movement_path_search;
+mod movement_profile;
+mod occupancy;
+mod path_search;
+mod point;
+mod quadtree;
+mod ray;
+mod rectangle;
+mod region;
+mod region_behavior;
+mod region_shape;
+mod search_context;
+mod sneak_options;
+mod token_document;
+mod token_find_movement_path_waypoint;
+mod token_movement_waypoint;
+mod token_shape_data;
+mod wall;
+
+pub use self::elevation_window::ElevationWindow;
+pub use self::find_movement_path_options::FindMovementPathOptions;
+pub use self::find_movement_path_result::FindMovementPathResult;
+pub use self::find_movement_path_result::FindMovementPathSegment;
+pub use self::grid_measure_path_result::GridMeasurePathResult;
+pub use self::grid_offset::GridOffset2D;
+pub use self::grid_offset::GridOffset3D;
+pub use self::hexagonal_grid_cube::HexagonalGridCube2D;
+pub use self::hexagonal_grid_cube::HexagonalGridCube3D;
+pub use self::line_intersection::LineIntersection;
+pub use self::movement_path_search::MovementPathSearch;
+pub use self::movement_profile::MovementProfile;
+pub use self::occupancy::Occupancy;
+pub use self::path_search::PathSearch;
+pub use self::point::ElevatedPoint;
+pub use self::point::Point;
+pub use self::quadtree::Quadtree;
+pub use self::ray::Ray;
+pub use self::rectangle::Rectangle;
+pub use self::region::Region;
+pub use self::region::RegionElevation;
+pub use self::region::RegionFlags;
+pub use self::region_behavior::RegionBehavior;
+pub use self::region_behavior::RegionBehaviorSystem;
+pub use self::region_shape::RegionShape;
+pub use self::search_context::SearchContext;
+pub use self::sneak_options::Observer;
+pub use self::sneak_options::SneakOptions;
+pub use self::token_document::TokenDocument;
+pub use self::token_find_movement_path_waypoint::TokenFindMovementPathWaypoint;
+pub use self::token_movement_waypoint::TokenMovementWaypoint;
+pub use self::token_shape_data::TokenHexagonalShapeData;
+pub use self::token_shape_data::TokenSquareShapeData;
+pub use self::wall::Wall;
+pub use self::wall::WallFlags;
+pub use self::wall::WallHeight;
+pub use self::wall::WallThreshold;
@@ -0,0 +1,84 @@
+use crate::{
+    scene::SceneState,
+    traits::{AStar, Node, TokenShape},
+    types::{
+        FindMovementPathOptions, FindMovementPathResult, GridMeasurePathResult, Occupancy, PathSearch, SearchContext,
+        TokenDocument, TokenMovementWaypoint,
+    },
+};
+
+/// How many nodes are expanded in each step of a search, fewer on gridless grids, see [`AStar::step_path`]
+const EXPANSIONS_PER_STEP: usize = 2000;
+
+/// A search for the path of a token through a scene, the same way `findMovementPath` does it
+///
+/// The search runs in steps, so the caller can check for a timeout or cancellation, or let other work happen, between
+/// them. The scene is only borrowed while stepping and can change in between.
+pub struct MovementPathSearch<N, T> {
+    search: PathSearch<N, T>,
+    options: FindMovementPathOptions,
+    /// The other tokens are where they were when the search started
+    occupancy: Occupancy,
+}
+
+impl<N: Node + Eq, T: TokenShape> MovementPathSearch<N, T> {
+    pub fn new<G: AStar<N, T>>(
+        grid: &G,
+        scene: &SceneState,
+        token: &TokenDocument,
+        waypoints: Vec<TokenMovementWaypoint>,
+        options: FindMovementPathOptions,
+        grid_measure_path_result: &GridMeasurePathResult,
+    ) -> Self {
+        let occupancy =
+            if options.ignore_tokens { Occupancy::default() } else { grid.get_occupancy(token, &scene.tokens) };
+
+        MovementPathSearch {
+            search: grid.start_path(waypoints, token, &options, grid_measure_path_result),
+            options,
+            occupancy,
+        }
+    }
+
+    /// Expands up to a few thousand nodes, returning the result once the search is finished
+    ///
+    /// Once `expired` the search is truncated, the path then ends at the node closest to the current waypoint.
+    pub fn step<G: AStar<N, T>>(
+        &mut self,
+        grid: &G,
+        scene: &SceneState,
+        expired: bool,
+    ) -> Option<FindMovementPathResult> {
+        if expired {
+            self.search.truncated = true;
+        }
+
+        let context = SearchContext {
+            bounds: &scene.bounds,
+            walls: &scene.walls,
+            regions: &scene.regions,
+            fog: if self.options.use_exploration { &scene.fog } else { &None },
+            exploration_coverage: self.options.exploration_coverage,
+            occupancy: &self.occupancy,
+            sneak: self.options.sneak.as_ref(),
+            vertices: &[],
+        };
+
+        let mut budget = EXPANSIONS_PER_STEP;
+        grid.step_path(&mut self.search, &context, &mut budget)
+    }
+
+    /// Steps through the whole search, checking if it's `expired` before each step
+    pub fn run<G: AStar<N, T>>(
+        mut self,
+        grid: &G,
+        scene: &SceneState,
+        mut expired: impl FnMut() -> bool,
+    ) -> FindMovementPathResult {
+        loop {
+            if let Some(result) = self.step(grid, scene, expired()) {
+                return result;
+            }
+        }
+    }
+}
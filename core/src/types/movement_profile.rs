@@ -0,0 +1,59 @@
+/// The rules used when searching for a path with a movement action
+#[derive(Clone)]
+#[derive(Debug)]
+pub struct MovementProfile {
+    pub action: String,
+    /// Ignores the movement cost of regions set with `flags.wayfinder.movementCost`, which is treated as ground terrain
+    pub ignore_difficult_terrain: bool,
+    /// Can move up or down at any point, instead of only towards the elevation of the next waypoint
+    pub free_elevation: bool,
+    /// Multiplies the cost of moves that change elevation, see [`MovementProfile::with_vertical_cost`]
+    pub vertical_cost: f64,
+    /// Can pass through walls with `flags.wayfinder.burrow` set
+    pub burrow: bool,
+    /// Can only move through regions with `flags.wayfinder.water` set
+    pub water_only: bool,
+}
+
+impl MovementProfile {
+    pub fn new(action: &str) -> Self {
+        let profile = MovementProfile {
+            action: action.to_string(),
+            ignore_difficult_terrain: false,
+            free_elevation: false,
+            vertical_cost: 1.0,
+            burrow: false,
+            water_only: false,
+        };
+
+        match action {
+            "fly" => MovementProfile { ignore_difficult_terrain: true, free_elevation: true, ..profile },
+            "burrow" => MovementProfile { burrow: true, free_elevation: true, ..profile },
+            "swim" => MovementProfile { water_only: true, free_elevation: true, ..profile },
+            _ => profile,
+        }
+    }
+
+    /// Changing elevation costs `vertical_cost` times as much, except for actions that move up and down as easily as
+    /// they move across (flying, burrowing, climbing, and swimming)
+    pub fn with_vertical_cost(self, vertical_cost: Option<f64>) -> Self {
+        match vertical_cost {
+            Some(_) if matches!(self.action.as_str(), "fly" | "burrow" | "climb" | "swim") => self,
+            Some(vertical_cost) => MovementProfile { vertical_cost, ..self },
+            None => self,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vertical_cost_only_applies_to_walking() {
+        assert_eq!(MovementProfile::new("walk").vertical_cost, 1.0);
+        assert_eq!(MovementProfile::new("walk").with_vertical_cost(Some(2.0)).vertical_cost, 2.0);
+        assert_eq!(MovementProfile::new("fly").with_vertical_cost(Some(2.0)).vertical_cost, 1.0);
+        assert_eq!(MovementProfile::new("climb").with_vertical_cost(Some(2.0)).vertical_cost, 1.0);
+    }
+}
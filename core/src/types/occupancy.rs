@@ -0,0 +1,53 @@
+use crate::types::{GridOffset3D, Point, Rectangle};
+use std::collections::HashSet;
+
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(Default)]
+pub struct Occupancy {
+    pub hostile: HashSet<GridOffset3D>,
+    pub allied: HashSet<GridOffset3D>,
+    pub hostile_bounds: Vec<(Rectangle, i32)>,
+    pub allied_bounds: Vec<(Rectangle, i32)>,
+}
+
+impl Occupancy {
+    pub fn is_blocked(&self, offsets: &[GridOffset3D]) -> bool {
+        offsets.iter().any(|offset| self.hostile.contains(offset))
+    }
+
+    pub fn is_occupied(&self, offsets: &[GridOffset3D]) -> bool {
+        offsets.iter().any(|offset| self.hostile.contains(offset) || self.allied.contains(offset))
+    }
+
+    pub fn blocks_segment(&self, start: Point, end: Point, k: i32, radius: f64) -> bool {
+        self.hostile_bounds
+            .iter()
+            .filter(|(_bounds, elevation)| *elevation == k)
+            .any(|(bounds, _elevation)| bounds.pad(radius).intersects_segment(start, end))
+    }
+
+    pub fn overlaps_bounds(&self, bounds: &Rectangle, k: i32) -> bool {
+        self.hostile_bounds
+            .iter()
+            .chain(self.allied_bounds.iter())
+            .filter(|(_bounds, elevation)| *elevation == k)
+            .any(|(other, _elevation)| other.intersects(bounds))
+    }
+
+    pub fn get_corners(&self, radius: f64) -> Vec<Point> {
+        self.hostile_bounds
+            .iter()
+            .flat_map(|(bounds, _elevation)| {
+                let bounds = bounds.pad(radius);
+
+                [
+                    Point::new(bounds.left(), bounds.top()),
+                    Point::new(bounds.right(), bounds.top()),
+                    Point::new(bounds.right(), bounds.bottom()),
+                    Point::new(bounds.left(), bounds.bottom()),
+                ]
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,27 @@
+use crate::{
+    modules::search::Search,
+    types::{ElevationWindow, FindMovementPathSegment, Point, TokenMovementWaypoint},
+};
+
+/// The state of a path search through a list of waypoints, so it can be resumed between steps
+pub struct PathSearch<N, T> {
+    pub waypoints: Vec<TokenMovementWaypoint>,
+    /// The index of the waypoint currently being searched for
+    pub index: usize,
+    pub start_node: N,
+    pub token_shape: T,
+    pub search: Option<Search<N>>,
+    /// The points the token could move to on the current leg, see [`BaseGrid::get_vertices`](crate::traits::BaseGrid::get_vertices)
+    pub vertices: Vec<Point>,
+    pub path: Vec<TokenMovementWaypoint>,
+    pub segments: Vec<FindMovementPathSegment>,
+    pub unreachable: Option<usize>,
+    pub closest: bool,
+    pub elevation_window: Option<ElevationWindow>,
+    pub vertical_cost: Option<f64>,
+    /// The number of nodes expanded so far and the most that may be expanded
+    pub expanded: usize,
+    pub max_nodes: Option<usize>,
+    /// Set once a limit is reached, the path then ends at the node closest to the current waypoint
+    pub truncated: bool,
+}
@@ -0,0 +1,295 @@
+use crate::types::Rectangle;
+use std::cell::Cell;
+
+/// A quadtree that keeps its nodes and objects in vectors and refers to them by index
+///
+/// Objects are stored in every leaf their bounds overlap, each query marks the objects it has visited so they're only
+/// visited once without having to collect them into a set.
+#[derive(Debug)]
+pub struct Quadtree<T> {
+    nodes: Vec<QuadtreeNode>,
+    objects: Vec<Option<QuadtreeObject<T>>>,
+    free: Vec<usize>,
+    query: Cell<u32>,
+    max_objects: usize,
+    max_depth: usize,
+}
+
+#[derive(Debug)]
+struct QuadtreeNode {
+    bounds: Rectangle,
+    depth: usize,
+    /// The index of the first of the four child nodes, if the node has been split
+    children: Option<usize>,
+    objects: Vec<usize>,
+}
+
+#[derive(Debug)]
+struct QuadtreeObject<T> {
+    r: Rectangle,
+    t: T,
+    /// The leaf nodes the object is stored in
+    leaves: Vec<usize>,
+    /// The last query that visited the object
+    query: Cell<u32>,
+}
+
+impl<T> Quadtree<T> {
+    pub fn new(bounds: Rectangle, max_depth: Option<usize>, max_objects: Option<usize>) -> Self {
+        Self {
+            nodes: vec![QuadtreeNode { bounds, depth: 0, children: None, objects: Vec::new() }],
+            objects: Vec::new(),
+            free: Vec::new(),
+            query: Cell::new(0),
+            max_objects: max_objects.unwrap_or(20),
+            max_depth: max_depth.unwrap_or(4),
+        }
+    }
+
+    /// Inserts an object, returning the key used to remove it
+    pub fn insert(&mut self, r: Rectangle, t: T) -> usize {
+        let object = QuadtreeObject { r, t, leaves: Vec::new(), query: Cell::new(0) };
+        let key = match self.free.pop() {
+            Some(key) => {
+                self.objects[key] = Some(object);
+                key
+            }
+            None => {
+                self.objects.push(Some(object));
+                self.objects.len() - 1
+            }
+        };
+
+        self.insert_into(0, key, &r);
+        key
+    }
+
+    pub fn remove(&mut self, key: usize) -> Option<T> {
+        let object = self.objects.get_mut(key)?.take()?;
+
+        for leaf in &object.leaves {
+            self.nodes[*leaf].objects.retain(|other| *other != key);
+        }
+
+        self.free.push(key);
+        Some(object.t)
+    }
+
+    pub fn get(&self, key: usize) -> Option<&T> {
+        self.objects.get(key)?.as_ref().map(|object| &object.t)
+    }
+
+    /// Every object in the quadtree
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.objects.iter().flatten().map(|object| &object.t)
+    }
+
+    pub fn clear(&mut self) {
+        self.nodes.truncate(1);
+        self.nodes[0].children = None;
+        self.nodes[0].objects.clear();
+        self.objects.clear();
+        self.free.clear();
+    }
+
+    /// Whether `predicate` returns true for any object whose bounds overlap `rect`
+    ///
+    /// Doesn't allocate, `predicate` can't query the same quadtree.
+    pub fn any<'a>(&'a self, rect: &Rectangle, mut predicate: impl FnMut(&'a T) -> bool) -> bool {
+        let query = self.next_query();
+        self.any_in(0, rect, query, &mut predicate)
+    }
+
+    /// The objects whose bounds overlap `rect`
+    pub fn get_objects(&self, rect: &Rectangle) -> Vec<&T> {
+        let mut objects = Vec::new();
+        self.any(rect, |object| {
+            objects.push(object);
+            false
+        });
+        objects
+    }
+
+    fn next_query(&self) -> u32 {
+        let query = self.query.get().wrapping_add(1);
+
+        // Objects could still be marked with a query from before the counter wrapped
+        if query == 0 {
+            self.objects.iter().flatten().for_each(|object| object.query.set(0));
+            self.query.set(1);
+            return 1;
+        }
+
+        self.query.set(query);
+        query
+    }
+
+    fn any_in<'a>(
+        &'a self,
+        node: usize,
+        rect: &Rectangle,
+        query: u32,
+        predicate: &mut impl FnMut(&'a T) -> bool,
+    ) -> bool {
+        if let Some(children) = self.nodes[node].children {
+            return get_child_nodes(&self.nodes[node].bounds, children, rect)
+                .any(|child| self.any_in(child, rect, query, predicate));
+        }
+
+        for key in &self.nodes[node].objects {
+            let object = self.objects[*key].as_ref().unwrap();
+            if object.query.replace(query) == query {
+                continue;
+            }
+
+            if rect.overlaps(&object.r) && predicate(&object.t) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn insert_into(&mut self, node: usize, key: usize, r: &Rectangle) {
+        let QuadtreeNode { depth, children, ref objects, .. } = self.nodes[node];
+        let full = objects.len() == (self.max_objects - 1);
+
+        if children.is_none() && full && depth < self.max_depth {
+            self.split(node);
+        }
+
+        match self.nodes[node].children {
+            Some(children) => {
+                let bounds = self.nodes[node].bounds;
+                for child in get_child_nodes(&bounds, children, r) {
+                    self.insert_into(child, key, r);
+                }
+            }
+            None => {
+                self.nodes[node].objects.push(key);
+                self.objects[key].as_mut().unwrap().leaves.push(node);
+            }
+        }
+    }
+
+    fn split(&mut self, node: usize) {
+        let QuadtreeNode { bounds: b, depth, .. } = self.nodes[node];
+        let w = b.width / 2.0;
+        let h = b.height / 2.0;
+        let children = self.nodes.len();
+
+        for bounds in [
+            Rectangle::new(b.x, b.y, w, h),
+            Rectangle::new(b.x + w, b.y, w, h),
+            Rectangle::new(b.x, b.y + h, w, h),
+            Rectangle::new(b.x + w, b.y + h, w, h),
+        ] {
+            self.nodes.push(QuadtreeNode { bounds, depth: depth + 1, children: None, objects: Vec::new() });
+        }
+
+        self.nodes[node].children = Some(children);
+
+        for key in std::mem::take(&mut self.nodes[node].objects) {
+            let object = self.objects[key].as_mut().unwrap();
+            object.leaves.retain(|leaf| *leaf != node);
+
+            let r = object.r;
+            self.insert_into(node, key, &r);
+        }
+    }
+}
+
+/// The children of a node with `bounds` that `rect` overlaps, `children` is the index of the first one
+fn get_child_nodes(bounds: &Rectangle, children: usize, rect: &Rectangle) -> impl Iterator<Item = usize> {
+    let hx = bounds.x + (bounds.width / 2.0);
+    let hy = bounds.y + (bounds.height / 2.0);
+
+    let start_top = rect.y <= hy;
+    let start_left = rect.x <= hx;
+    let end_bottom = (rect.y + rect.height) > hy;
+    let end_right = (rect.x + rect.width) > hx;
+
+    [start_left && start_top, end_right && start_top, start_left && end_bottom, end_right && end_bottom]
+        .into_iter()
+        .enumerate()
+        .filter(|(_, overlaps)| *overlaps)
+        .map(move |(index, _)| children + index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quadtree() -> Quadtree<usize> {
+        Quadtree::new(Rectangle::new(0.0, 0.0, 1000.0, 1000.0), Some(4), Some(4))
+    }
+
+    fn sorted(mut objects: Vec<&usize>) -> Vec<usize> {
+        objects.sort();
+        objects.into_iter().copied().collect()
+    }
+
+    #[test]
+    fn objects_spanning_several_leaves_are_found_once() {
+        let mut quadtree = quadtree();
+        for i in 0..10 {
+            let x = i as f64 * 100.0;
+            quadtree.insert(Rectangle::new(x, x, 10.0, 10.0), i);
+        }
+        quadtree.insert(Rectangle::new(0.0, 500.0, 1000.0, 0.0), 10);
+
+        assert!(quadtree.nodes.len() > 1);
+        assert_eq!(
+            sorted(quadtree.get_objects(&Rectangle::new(0.0, 0.0, 1000.0, 1000.0))),
+            (0..=10).collect::<Vec<_>>()
+        );
+        assert_eq!(sorted(quadtree.get_objects(&Rectangle::new(450.0, 450.0, 100.0, 100.0))), vec![5, 10]);
+        assert_eq!(sorted(quadtree.get_objects(&Rectangle::new(900.0, 100.0, 50.0, 50.0))), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn any_stops_at_the_first_match() {
+        let mut quadtree = quadtree();
+        for i in 0..10 {
+            quadtree.insert(Rectangle::new(0.0, 0.0, 1000.0, 1000.0), i);
+        }
+
+        let mut visited = 0;
+        assert!(quadtree.any(&Rectangle::new(0.0, 0.0, 10.0, 10.0), |_| {
+            visited += 1;
+            true
+        }));
+        assert_eq!(visited, 1);
+        assert!(!quadtree.any(&Rectangle::new(0.0, 0.0, 10.0, 10.0), |i| *i == 10));
+    }
+
+    #[test]
+    fn removed_objects_are_no_longer_found() {
+        let mut quadtree = quadtree();
+        let keys: Vec<usize> =
+            (0..10).map(|i| quadtree.insert(Rectangle::new(i as f64 * 100.0, 0.0, 150.0, 10.0), i)).collect();
+
+        assert_eq!(quadtree.remove(keys[3]), Some(3));
+        assert_eq!(quadtree.remove(keys[3]), None);
+        assert_eq!(quadtree.get(keys[3]), None);
+        assert_eq!(sorted(quadtree.get_objects(&Rectangle::new(300.0, 0.0, 10.0, 10.0))), vec![2]);
+
+        // The key is reused by the next object
+        assert_eq!(quadtree.insert(Rectangle::new(300.0, 0.0, 10.0, 10.0), 11), keys[3]);
+        assert_eq!(sorted(quadtree.get_objects(&Rectangle::new(300.0, 0.0, 10.0, 10.0))), vec![2, 11]);
+        assert_eq!(quadtree.iter().count(), 10);
+    }
+
+    #[test]
+    fn objects_outside_the_bounds_are_kept() {
+        let mut quadtree = quadtree();
+        for i in 0..10 {
+            quadtree.insert(Rectangle::new(i as f64 * 100.0, 0.0, 10.0, 10.0), i);
+        }
+        quadtree.insert(Rectangle::new(-500.0, -500.0, 10.0, 10.0), 10);
+        quadtree.insert(Rectangle::new(1500.0, 1500.0, 10.0, 10.0), 11);
+
+        assert_eq!(sorted(quadtree.get_objects(&Rectangle::new(-500.0, -500.0, 0.0, 0.0))), vec![10]);
+        assert_eq!(sorted(quadtree.get_objects(&Rectangle::new(1505.0, 1505.0, 0.0, 0.0))), vec![11]);
+    }
+}
@@ -12,7 +12,6 @@ pub struct Ray {
     y0: f64,
     dx: f64,
     dy: f64,
-    slope: f64,
     angle: Option<f64>,
     distance: Option<f64>,
 }
@@ -23,16 +22,15 @@ impl Ray {
         let y0 = a.y;
         let dx = b.x - a.x;
         let dy = b.y - a.y;
-        let slope = dy / dx;
 
-        Self { a, b, x0, y0, dx, dy, slope, angle: None, distance: None }
+        Self { a, b, x0, y0, dx, dy, angle: None, distance: None }
     }
 
     pub fn angle(mut self) -> f64 {
         if self.angle.is_none() {
             self.angle = Some(f64::atan2(self.dy, self.dx));
         }
-        return self.angle.unwrap();
+        self.angle.unwrap()
     }
 
     pub fn bounds(self) -> Rectangle {
@@ -43,7 +41,7 @@ impl Ray {
         if self.distance.is_none() {
             self.distance = Some(f64::hypot(self.dx, self.dy));
         }
-        return self.distance.unwrap();
+        self.distance.unwrap()
     }
 
     pub fn from_angle(x: f64, y: f64, radians: f64, distance: f64) -> Self {
@@ -51,21 +49,18 @@ impl Ray {
         let y0 = y;
         let dx = radians.cos();
         let dy = radians.sin();
-        let slope = dy / dx;
         let angle = math::normalize_radians(radians);
-        let distance = distance;
 
-        return Self {
+        Self {
             a: Point { x, y },
             b: Point { x: x + (dx * distance), y: y + (dy * distance) },
             x0,
             y0,
             dx,
             dy,
-            slope,
             angle: Some(angle),
             distance: Some(distance),
-        };
+        }
     }
 
     pub fn from_arrays(a: [f64; 2], b: [f64; 2]) -> Self {
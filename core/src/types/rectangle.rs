@@ -0,0 +1,102 @@
+use crate::{modules::geometry, types::Point};
+
+#[derive(Clone, Copy)]
+#[derive(Debug)]
+pub struct Rectangle {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rectangle {
+    pub fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self { x, y, width, height }
+    }
+
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        if self.width <= 0.0 || self.height <= 0.0 {
+            return false;
+        }
+
+        if x >= self.left() && x < self.right() && y >= self.top() && y < self.bottom() {
+            return true;
+        }
+
+        false
+    }
+
+    pub fn contains_point(&self, Point { x, y }: Point) -> bool {
+        self.contains(x, y)
+    }
+
+    pub fn normalize(&self) -> Rectangle {
+        let Rectangle { mut x, mut y, mut width, mut height } = *self;
+
+        if width < 0.0 {
+            x += width;
+            width = width.abs();
+        }
+
+        if height < 0.0 {
+            y += height;
+            height = height.abs();
+        }
+
+        Rectangle { x, y, width, height }
+    }
+
+    pub fn overlaps(&self, other: &Rectangle) -> bool {
+        other.right() >= self.left()
+            && other.left() <= self.right()
+            && other.bottom() >= self.top()
+            && other.top() <= self.bottom()
+    }
+
+    pub fn intersects(&self, other: &Rectangle) -> bool {
+        other.right() > self.left()
+            && other.left() < self.right()
+            && other.bottom() > self.top()
+            && other.top() < self.bottom()
+    }
+
+    pub fn intersects_segment(&self, a: Point, b: Point) -> bool {
+        if self.contains_point(a) || self.contains_point(b) {
+            return true;
+        }
+
+        let corners = [
+            Point::new(self.left(), self.top()),
+            Point::new(self.right(), self.top()),
+            Point::new(self.right(), self.bottom()),
+            Point::new(self.left(), self.bottom()),
+        ];
+
+        (0..4).any(|i| geometry::line_segment_intersection(&a, &b, &corners[i], &corners[(i + 1) % 4], None).is_some())
+    }
+
+    pub fn pad(&self, padding: f64) -> Rectangle {
+        Rectangle {
+            x: self.x - padding,
+            y: self.y - padding,
+            width: self.width + (padding * 2.0),
+            height: self.height + (padding * 2.0),
+        }
+    }
+
+    pub fn left(&self) -> f64 {
+        self.x
+    }
+
+    pub fn right(&self) -> f64 {
+        self.x + self.width
+    }
+
+    pub fn top(&self) -> f64 {
+        self.y
+    }
+
+    pub fn bottom(&self) -> f64 {
+        self.y + self.height
+    }
+}
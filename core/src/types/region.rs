@@ -0,0 +1,141 @@
+use crate::{
+    enums::RegionVisibility,
+    modules::geometry,
+    types::{ElevatedPoint, MovementProfile, Point, Rectangle, RegionBehavior, RegionShape},
+};
+use clipper2::{Centi, EndType, FillRule, JoinType, Paths};
+
+#[derive(Clone)]
+#[derive(Debug)]
+pub struct RegionElevation {
+    pub bottom: f64,
+    pub top: f64,
+}
+
+#[derive(Clone)]
+#[derive(Debug)]
+pub struct RegionFlags {
+    pub impassable: bool,
+    pub movement_cost: Option<f64>,
+    pub water: bool,
+}
+
+#[derive(Clone)]
+#[derive(Debug)]
+pub struct Region {
+    pub id: String,
+    pub name: String,
+    pub color: String,
+    pub shapes: Vec<RegionShape>,
+    pub elevation: RegionElevation,
+    pub behaviors: Vec<RegionBehavior>,
+    pub visibility: RegionVisibility,
+    pub locked: bool,
+    pub flags: RegionFlags,
+    pub polygons: Vec<Vec<Point>>,
+    pub bounds: Rectangle,
+}
+
+impl Region {
+    pub fn create_polygons(shapes: &[RegionShape]) -> Vec<Vec<Point>> {
+        let to_paths = |hole: bool| -> Paths<Centi> {
+            shapes
+                .iter()
+                .filter(|shape| shape.is_hole() == hole)
+                .map(|shape| {
+                    let mut points = shape.to_polygon();
+                    if geometry::polygon_signed_area(&points) < 0.0 {
+                        points.reverse();
+                    }
+                    points.into_iter().map(|Point { x, y }| (x, y)).collect::<Vec<(f64, f64)>>()
+                })
+                .filter(|points| points.len() >= 3)
+                .collect::<Vec<Vec<(f64, f64)>>>()
+                .into()
+        };
+
+        let solids = to_paths(false);
+        let holes = to_paths(true);
+
+        if solids.is_empty() {
+            return Vec::new();
+        }
+
+        match solids.to_clipper_subject().add_clip(holes).difference(FillRule::NonZero) {
+            Ok(paths) => Region::from_paths(paths),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    fn from_paths(paths: Paths<Centi>) -> Vec<Vec<Point>> {
+        let paths: Vec<Vec<(f64, f64)>> = paths.into();
+        paths.into_iter().map(|path| path.into_iter().map(|(x, y)| Point::new(x, y)).collect()).collect()
+    }
+
+    pub fn contains_elevation(&self, elevation: f64) -> bool {
+        elevation >= self.elevation.bottom && elevation <= self.elevation.top
+    }
+
+    pub fn contains_point(&self, ElevatedPoint { x, y, elevation }: ElevatedPoint) -> bool {
+        if !self.contains_elevation(elevation) {
+            return false;
+        }
+
+        let point = Point::new(x, y);
+        if !self.bounds.contains_point(point) {
+            return false;
+        }
+
+        self.polygons.iter().filter(|polygon| geometry::polygon_contains_point(polygon, &point)).count() % 2 == 1
+    }
+
+    pub fn intersects_segment(&self, a: ElevatedPoint, b: ElevatedPoint) -> bool {
+        if !self.contains_elevation(a.elevation) && !self.contains_elevation(b.elevation) {
+            return false;
+        }
+
+        if !self.bounds.overlaps(&Rectangle::new(a.x, a.y, b.x - a.x, b.y - a.y).normalize()) {
+            return false;
+        }
+
+        if self.contains_point(a) || self.contains_point(b) {
+            return true;
+        }
+
+        let a = Point::from(a);
+        let b = Point::from(b);
+
+        self.polygons.iter().any(|polygon| {
+            polygon
+                .iter()
+                .zip(polygon.iter().cycle().skip(1))
+                .any(|(c, d)| geometry::line_segment_intersection(&a, &b, c, d, None).is_some())
+        })
+    }
+
+    pub fn get_corners(&self, radius: f64) -> Vec<Point> {
+        let paths: Paths<Centi> = self
+            .polygons
+            .iter()
+            .map(|polygon| polygon.iter().map(|Point { x, y }| (*x, *y)).collect::<Vec<(f64, f64)>>())
+            .collect::<Vec<Vec<(f64, f64)>>>()
+            .into();
+
+        Region::from_paths(paths.inflate(radius, JoinType::Miter, EndType::Polygon, 2.0).simplify(1.0, false))
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
+    pub fn get_cost_multiplier(&self, profile: &MovementProfile) -> Option<f64> {
+        if self.flags.impassable {
+            return Some(f64::INFINITY);
+        }
+
+        self.behaviors
+            .iter()
+            .filter_map(|behavior| behavior.get_cost_multiplier(&profile.action))
+            .chain(self.flags.movement_cost.filter(|_| !profile.ignore_difficult_terrain))
+            .reduce(f64::max)
+    }
+}
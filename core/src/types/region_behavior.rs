@@ -0,0 +1,30 @@
+use std::collections::HashMap;
+
+#[derive(Clone)]
+#[derive(Debug)]
+pub enum RegionBehaviorSystem {
+    ModifyMovementCost { difficulties: HashMap<String, f64> },
+    Other,
+}
+
+#[derive(Clone)]
+#[derive(Debug)]
+pub struct RegionBehavior {
+    pub id: String,
+    pub name: String,
+    pub system: RegionBehaviorSystem,
+    pub disabled: bool,
+}
+
+impl RegionBehavior {
+    pub fn get_cost_multiplier(&self, action: &str) -> Option<f64> {
+        if self.disabled {
+            return None;
+        }
+
+        match &self.system {
+            RegionBehaviorSystem::ModifyMovementCost { difficulties } => difficulties.get(action).copied(),
+            RegionBehaviorSystem::Other => None,
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{modules::geometry, types::Point};
+
+#[derive(Clone)]
+#[derive(Debug)]
+pub enum RegionShape {
+    Rectangle { x: f64, y: f64, width: f64, height: f64, rotation: f64, hole: bool },
+    Circle { x: f64, y: f64, radius: f64, hole: bool },
+    Ellipse { x: f64, y: f64, radius_x: f64, radius_y: f64, rotation: f64, hole: bool },
+    Polygon { points: Vec<Point>, hole: bool },
+}
+
+impl RegionShape {
+    pub fn is_hole(&self) -> bool {
+        match self {
+            RegionShape::Rectangle { hole, .. } => *hole,
+            RegionShape::Circle { hole, .. } => *hole,
+            RegionShape::Ellipse { hole, .. } => *hole,
+            RegionShape::Polygon { hole, .. } => *hole,
+        }
+    }
+
+    pub fn to_polygon(&self) -> Vec<Point> {
+        match self {
+            RegionShape::Rectangle { x, y, width, height, rotation, hole: _ } => {
+                let center = Point::new(x + (width / 2.0), y + (height / 2.0));
+
+                [
+                    Point::new(*x, *y),
+                    Point::new(x + width, *y),
+                    Point::new(x + width, y + height),
+                    Point::new(*x, y + height),
+                ]
+                .iter()
+                .map(|point| geometry::rotate_point(point, &center, rotation.to_radians()))
+                .collect()
+            }
+            RegionShape::Circle { x, y, radius, hole: _ } => {
+                RegionShape::ellipse_to_polygon(Point::new(*x, *y), *radius, *radius, 0.0)
+            }
+            RegionShape::Ellipse { x, y, radius_x, radius_y, rotation, hole: _ } => {
+                RegionShape::ellipse_to_polygon(Point::new(*x, *y), *radius_x, *radius_y, rotation.to_radians())
+            }
+            RegionShape::Polygon { points, hole: _ } => points.clone(),
+        }
+    }
+
+    fn ellipse_to_polygon(center: Point, radius_x: f64, radius_y: f64, rotation: f64) -> Vec<Point> {
+        if radius_x <= 0.0 || radius_y <= 0.0 {
+            return Vec::new();
+        }
+
+        let density = geometry::approximate_vertex_density(f64::max(radius_x, radius_y), None);
+
+        (0..density)
+            .map(|i| {
+                let angle = (i as f64 / density as f64) * std::f64::consts::TAU;
+                let point = Point::new(center.x + (radius_x * angle.cos()), center.y + (radius_y * angle.sin()));
+                geometry::rotate_point(&point, &center, rotation)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{
+    scene::{Fog, Regions, Walls},
+    types::{Occupancy, Point, Rectangle, SneakOptions},
+};
+
+#[derive(Clone, Copy)]
+pub struct SearchContext<'a> {
+    pub bounds: &'a Rectangle,
+    pub walls: &'a Walls,
+    pub regions: &'a Regions,
+    pub fog: &'a Option<Fog>,
+    /// Check every space the token covers against the fog instead of only its center, see
+    /// [`FindMovementPathOptions::exploration_coverage`](crate::types::FindMovementPathOptions)
+    pub exploration_coverage: Option<f64>,
+    pub occupancy: &'a Occupancy,
+    pub sneak: Option<&'a SneakOptions>,
+    /// The points a gridless token could move to on the current leg, filled in by
+    /// [`AStar::step_path`](crate::traits::AStar::step_path) from
+    /// [`BaseGrid::get_vertices`](crate::traits::BaseGrid::get_vertices)
+    pub vertices: &'a [Point],
+}
@@ -0,0 +1,47 @@
+use crate::{scene::Walls, types::Point};
+
+/// A token the path should stay out of sight of
+#[derive(Clone, Copy)]
+#[derive(Debug)]
+pub struct Observer {
+    pub x: f64,
+    pub y: f64,
+    /// How far the observer can see, in pixels
+    pub radius: f64,
+}
+
+/// Keeps a path out of sight of observers, sight is blocked by walls that block vision
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(Default)]
+pub struct SneakOptions {
+    pub observers: Vec<Observer>,
+    /// What the cost of entering a space an observer can see is multiplied by, if `None` those spaces can't be entered
+    pub penalty: Option<f64>,
+}
+
+impl Observer {
+    pub fn new(x: f64, y: f64, radius: f64) -> Self {
+        Self { x, y, radius }
+    }
+
+    pub fn can_see(&self, point: Point, walls: &Walls) -> bool {
+        let origin = Point::new(self.x, self.y);
+        f64::hypot(point.x - origin.x, point.y - origin.y) <= self.radius && !walls.check_sight(origin, point)
+    }
+}
+
+impl SneakOptions {
+    pub fn is_visible(&self, point: Point, walls: &Walls) -> bool {
+        self.observers.iter().any(|observer| observer.can_see(point, walls))
+    }
+
+    /// The cost multiplier for entering a space, `points` are the centers of the grid spaces the token would cover
+    pub fn get_cost_multiplier(&self, points: impl IntoIterator<Item = Point>, walls: &Walls) -> f64 {
+        if points.into_iter().any(|point| self.is_visible(point, walls)) {
+            self.penalty.unwrap_or(f64::INFINITY)
+        } else {
+            1.0
+        }
+    }
+}
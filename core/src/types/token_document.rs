@@ -0,0 +1,36 @@
+use crate::{
+    enums::{TokenDisposition, TokenShapeType},
+    types::TokenMovementWaypoint,
+};
+
+#[derive(Clone)]
+#[derive(Debug)]
+pub struct TokenDocument {
+    pub id: String,
+    pub x: f64,
+    pub y: f64,
+    pub elevation: f64,
+    pub width: f64,
+    pub height: f64,
+    pub shape: TokenShapeType,
+    pub movement_action: String,
+    pub disposition: TokenDisposition,
+    pub hidden: bool,
+}
+
+impl TokenDocument {
+    pub fn create_waypoint(&self) -> TokenMovementWaypoint {
+        TokenMovementWaypoint {
+            x: self.x,
+            y: self.y,
+            elevation: self.elevation,
+            width: self.width,
+            height: self.height,
+            shape: self.shape,
+            action: self.movement_action.clone(),
+            snapped: false,
+            explicit: false,
+            checkpoint: false,
+        }
+    }
+}
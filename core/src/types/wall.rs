@@ -0,0 +1,155 @@
+use crate::{
+    enums::{WallDirection, WallDoorState, WallDoorType, WallMovementType, WallRestrictionType, WallSenseType},
+    modules::geometry,
+    types::{MovementProfile, Point},
+};
+
+use super::Rectangle;
+
+#[derive(Clone)]
+#[derive(Debug)]
+pub struct Wall {
+    pub id: String,
+    pub c: [f64; 4],
+    pub light: WallSenseType,
+    pub r#move: WallMovementType,
+    pub sight: WallSenseType,
+    pub sound: WallSenseType,
+    pub dir: WallDirection,
+    pub door: WallDoorType,
+    pub ds: WallDoorState,
+    pub flags: WallFlags,
+    pub height: WallHeight,
+    pub threshold: WallThreshold,
+}
+
+#[derive(Clone)]
+#[derive(Debug)]
+pub struct WallFlags {
+    pub burrow: bool,
+}
+
+/// The elevation range of the wall, from the flags set by the Wall Height module
+#[derive(Clone)]
+#[derive(Debug)]
+pub struct WallHeight {
+    pub bottom: f64,
+    pub top: f64,
+}
+
+/// How close a source has to be for proximity and distance walls to stop restricting it, in scene units
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(Default)]
+pub struct WallThreshold {
+    pub light: Option<f64>,
+    pub sight: Option<f64>,
+    pub sound: Option<f64>,
+}
+
+impl Wall {
+    pub fn blocks_movement(&self) -> bool {
+        if self.door == WallDoorType::None && self.r#move == WallMovementType::Normal {
+            return true;
+        }
+
+        if self.door != WallDoorType::None && self.ds != WallDoorState::Open {
+            return true;
+        }
+
+        false
+    }
+
+    /// How the wall restricts a type of source, open doors don't restrict anything
+    pub fn get_restriction(&self, restriction: WallRestrictionType) -> WallSenseType {
+        if self.door != WallDoorType::None && self.ds == WallDoorState::Open {
+            return WallSenseType::None;
+        }
+
+        match restriction {
+            WallRestrictionType::Light => self.light,
+            WallRestrictionType::Sight => self.sight,
+            WallRestrictionType::Sound => self.sound,
+            WallRestrictionType::Move => {
+                if self.blocks_movement() {
+                    WallSenseType::Normal
+                } else {
+                    WallSenseType::None
+                }
+            }
+        }
+    }
+
+    /// Whether a proximity or distance wall stops restricting a source at `origin`, the same as `Wall#applyThreshold`
+    ///
+    /// A proximity wall doesn't restrict sources closer than its threshold, and a distance wall doesn't restrict
+    /// sources farther than it. `pixels` is the number of pixels in a scene unit.
+    pub fn applies_threshold(&self, restriction: WallRestrictionType, origin: Point, pixels: f64) -> bool {
+        let threshold = match restriction {
+            WallRestrictionType::Light => self.threshold.light,
+            WallRestrictionType::Sight => self.threshold.sight,
+            WallRestrictionType::Sound => self.threshold.sound,
+            WallRestrictionType::Move => None,
+        };
+        let Some(threshold) = threshold.filter(|threshold| *threshold > 0.0) else {
+            return false;
+        };
+
+        let closest = geometry::closest_point_to_segment(&origin, &self.get_a(), &self.get_b()).unwrap_or(self.get_a());
+        let distance = f64::hypot(closest.x - origin.x, closest.y - origin.y);
+
+        match self.get_restriction(restriction) {
+            WallSenseType::Proximity => distance < threshold * pixels,
+            WallSenseType::Distance => distance > threshold * pixels,
+            _ => false,
+        }
+    }
+
+    pub fn blocks_profile(&self, profile: &MovementProfile) -> bool {
+        self.blocks_movement() && !(profile.burrow && self.flags.burrow)
+    }
+
+    /// Whether the wall blocks movement regardless of the movement action or elevation
+    pub fn always_blocks(&self) -> bool {
+        self.blocks_movement() && !self.flags.burrow && self.contains_every_elevation()
+    }
+
+    pub fn contains_every_elevation(&self) -> bool {
+        self.height.bottom == f64::NEG_INFINITY && self.height.top == f64::INFINITY
+    }
+
+    pub fn contains_elevation(&self, elevation: f64) -> bool {
+        elevation >= self.height.bottom && elevation < self.height.top
+    }
+
+    pub fn blocks_direction(&self, origin: &Point) -> bool {
+        self.dir == WallDirection::Both || self.orient_point(origin) != self.dir
+    }
+
+    pub fn orient_point(&self, point: &Point) -> WallDirection {
+        let orientation = geometry::orient_2d_fast(&self.get_a(), &self.get_b(), point);
+
+        if orientation == 0.0 {
+            WallDirection::Both
+        } else if orientation < 0.0 {
+            WallDirection::Left
+        } else {
+            WallDirection::Right
+        }
+    }
+
+    pub fn get_a(&self) -> Point {
+        Point::new(self.c[0], self.c[1])
+    }
+
+    pub fn get_b(&self) -> Point {
+        Point::new(self.c[2], self.c[3])
+    }
+
+    pub fn get_bounds(&self) -> Rectangle {
+        let Point { x: x0, y: y0 } = self.get_a();
+        let Point { x: x1, y: y1 } = self.get_b();
+
+        Rectangle::new(x0, y0, x1 - x0, y1 - y0).normalize()
+    }
+}
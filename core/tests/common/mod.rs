@@ -0,0 +1,242 @@
+use wayfinder_core::{
+    enums::{
+        Grid, GridDiagonals, RegionVisibility, TokenDisposition, TokenShapeType, WallDirection, WallDoorState,
+        WallDoorType, WallMovementType, WallSenseType,
+    },
+    grids::{GridlessGrid, HexagonalGrid, SquareGrid},
+    modules::geometry,
+    scene::{Fog, SceneState, Walls},
+    traits::{AStar, BaseGrid, Node, TokenShape},
+    types::{
+        ElevatedPoint, FindMovementPathOptions, FindMovementPathResult, GridMeasurePathResult, MovementPathSearch,
+        MovementProfile, Point, Rectangle, Region, RegionElevation, RegionFlags, RegionShape, TokenDocument,
+        TokenMovementWaypoint, Wall, WallFlags, WallHeight, WallThreshold,
+    },
+};
+
+pub const SIZE: i32 = 100;
+pub const DISTANCE: f64 = 5.0;
+
+/// A scene to search for paths in, built up from walls, regions, and tokens
+pub struct Scene {
+    pub bounds: Rectangle,
+    pub walls: Vec<Wall>,
+    pub regions: Vec<Region>,
+    pub tokens: Vec<TokenDocument>,
+    /// The explored parts of the scene, as polygons
+    pub explored: Option<Vec<Vec<Point>>>,
+}
+
+impl Scene {
+    pub fn new(width: f64, height: f64) -> Self {
+        let bounds = Rectangle::new(0.0, 0.0, width, height);
+
+        Scene { bounds, walls: Vec::new(), regions: Vec::new(), tokens: Vec::new(), explored: None }
+    }
+
+    pub fn with_walls(mut self, walls: Vec<Wall>) -> Self {
+        self.walls.extend(walls);
+        self
+    }
+
+    pub fn with_regions(mut self, regions: Vec<Region>) -> Self {
+        self.regions.extend(regions);
+        self
+    }
+
+    pub fn with_tokens(mut self, tokens: Vec<TokenDocument>) -> Self {
+        self.tokens.extend(tokens);
+        self
+    }
+
+    /// Marks the parts of the scene inside `rects` as explored, at one pixel for every 10x10 area
+    pub fn with_explored(mut self, rects: Vec<Rectangle>) -> Self {
+        let polygons: Vec<Vec<Point>> = rects
+            .iter()
+            .map(|rect| {
+                vec![
+                    Point::new(rect.left(), rect.top()),
+                    Point::new(rect.right(), rect.top()),
+                    Point::new(rect.right(), rect.bottom()),
+                    Point::new(rect.left(), rect.bottom()),
+                ]
+            })
+            .collect();
+
+        self.explored = Some(polygons);
+        self
+    }
+
+    pub fn get_walls<G: Clone + Into<Grid>>(&self, grid: &G) -> Walls {
+        Walls::new(self.bounds, &grid.clone().into(), self.walls.clone())
+    }
+
+    /// Finds a path for `token` from its position through each of the `points`, the same way `findMovementPath` does
+    pub fn find_path<N: Node + Eq, T: TokenShape, G: AStar<N, T> + Clone + Into<Grid>>(
+        &self,
+        grid: &G,
+        token: &TokenDocument,
+        points: &[(f64, f64)],
+        options: FindMovementPathOptions,
+    ) -> FindMovementPathResult {
+        let start = token.create_waypoint();
+        let waypoints = std::iter::once(start.clone())
+            .chain(points.iter().map(|(x, y)| TokenMovementWaypoint { x: *x, y: *y, ..start.clone() }))
+            .collect();
+
+        let grid_type: Grid = grid.clone().into();
+        let mut scene =
+            SceneState::new(self.bounds, &grid_type, self.walls.clone(), self.regions.clone(), self.tokens.clone());
+        scene.fog = self.explored.as_ref().map(|polygons| Fog::from_polygons(self.bounds, 0.1, polygons));
+
+        let search = MovementPathSearch::new(grid, &scene, token, waypoints, options, &measure_path_result());
+        search.run(grid, &scene, || false)
+    }
+
+    /// Whether the center of `token` passes through a wall anywhere along the path
+    pub fn path_collides<N: Node + Eq, T: TokenShape, G: AStar<N, T> + Clone + Into<Grid>>(
+        &self,
+        grid: &G,
+        token: &TokenDocument,
+        result: &FindMovementPathResult,
+    ) -> bool {
+        let shape = grid.get_token_shape(token.width, token.height, token.shape);
+        let centers: Vec<ElevatedPoint> = result
+            .waypoints
+            .iter()
+            .map(|waypoint| grid.get_token_center_point(waypoint.create_elevated_point(), &shape))
+            .collect();
+
+        let walls = self.get_walls(grid);
+        centers.windows(2).any(|pair| walls.check_collision(pair[0], pair[1], &MovementProfile::new("walk")))
+    }
+
+    /// Whether a gridless `token` comes closer to a wall than its radius anywhere along the path
+    pub fn path_touches_walls(
+        &self,
+        grid: &GridlessGrid,
+        token: &TokenDocument,
+        result: &FindMovementPathResult,
+    ) -> bool {
+        let shape = grid.get_token_shape(token.width, token.height, token.shape);
+        let radius = f64::max(shape.width, shape.height) * grid.size as f64 / 2.0;
+        let centers: Vec<Point> = result
+            .waypoints
+            .iter()
+            .map(|waypoint| grid.get_token_center_point(waypoint.create_elevated_point(), &shape).into())
+            .collect();
+
+        centers.windows(2).any(|pair| {
+            self.walls.iter().any(|wall| {
+                geometry::segment_distance(&pair[0], &pair[1], &wall.get_a(), &wall.get_b()) < radius - 1e-6
+            })
+        })
+    }
+}
+
+/// An empty 10x10 room
+pub fn open_room() -> Scene {
+    Scene::new(1000.0, 1000.0)
+}
+
+/// A 10x10 room split down the middle by a wall, with a one space gap at the bottom
+pub fn divided_room() -> Scene {
+    open_room().with_walls(vec![wall("divider", [300.0, 0.0, 300.0, 900.0])])
+}
+
+/// A 10x10 room with the space at (5, 5) walled off on every side
+pub fn closet_room() -> Scene {
+    open_room().with_walls(vec![
+        wall("top", [500.0, 500.0, 600.0, 500.0]),
+        wall("right", [600.0, 500.0, 600.0, 600.0]),
+        wall("bottom", [600.0, 600.0, 500.0, 600.0]),
+        wall("left", [500.0, 600.0, 500.0, 500.0]),
+    ])
+}
+
+/// A 10x10 room with a one space wide corridor along the top
+pub fn corridor_room() -> Scene {
+    open_room().with_walls(vec![wall("corridor", [0.0, 100.0, 1000.0, 100.0])])
+}
+
+/// A 10x10 room split down the middle by a wall one space high, that can only be passed above elevation 5
+pub fn low_wall_room() -> Scene {
+    open_room().with_walls(vec![low_wall("low", [300.0, 0.0, 300.0, 1000.0], f64::NEG_INFINITY, 5.0)])
+}
+
+pub fn square_grid(diagonals: GridDiagonals) -> SquareGrid {
+    SquareGrid { size: SIZE, distance: DISTANCE, diagonals }
+}
+
+pub fn hexagonal_grid(columns: bool) -> HexagonalGrid {
+    let size = SIZE as f64;
+    let (size_x, size_y) =
+        if columns { (size * 2.0 / 3.0_f64.sqrt(), size) } else { (size, size * 2.0 / 3.0_f64.sqrt()) };
+
+    HexagonalGrid { size: SIZE, distance: DISTANCE, size_x, size_y, columns, even: false }
+}
+
+pub fn gridless_grid() -> GridlessGrid {
+    GridlessGrid { size: SIZE, distance: DISTANCE }
+}
+
+pub fn measure_path_result() -> GridMeasurePathResult {
+    GridMeasurePathResult { distance: 0.0, cost: 0.0, spaces: 0, diagonals: 0, euclidean: 0.0 }
+}
+
+pub fn wall(id: &str, c: [f64; 4]) -> Wall {
+    Wall {
+        id: id.to_string(),
+        c,
+        light: WallSenseType::Normal,
+        r#move: WallMovementType::Normal,
+        sight: WallSenseType::Normal,
+        sound: WallSenseType::Normal,
+        dir: WallDirection::Both,
+        door: WallDoorType::None,
+        ds: WallDoorState::Closed,
+        flags: WallFlags { burrow: false },
+        height: WallHeight { bottom: f64::NEG_INFINITY, top: f64::INFINITY },
+        threshold: WallThreshold::default(),
+    }
+}
+
+/// A wall that only blocks movement from `bottom` up to `top`, the way Wall Height sets it
+pub fn low_wall(id: &str, c: [f64; 4], bottom: f64, top: f64) -> Wall {
+    Wall { height: WallHeight { bottom, top }, ..wall(id, c) }
+}
+
+pub fn token(id: &str, x: f64, y: f64, disposition: TokenDisposition) -> TokenDocument {
+    TokenDocument {
+        id: id.to_string(),
+        x,
+        y,
+        elevation: 0.0,
+        width: 1.0,
+        height: 1.0,
+        shape: TokenShapeType::Rectangle1,
+        movement_action: "walk".to_string(),
+        disposition,
+        hidden: false,
+    }
+}
+
+pub fn region(id: &str, shape: RegionShape, flags: RegionFlags) -> Region {
+    let shapes = vec![shape];
+    let polygons = Region::create_polygons(&shapes);
+    let bounds = geometry::polygons_bounds(&polygons);
+
+    Region {
+        id: id.to_string(),
+        name: id.to_string(),
+        color: "#000000".to_string(),
+        shapes,
+        elevation: RegionElevation { bottom: f64::NEG_INFINITY, top: f64::INFINITY },
+        behaviors: Vec::new(),
+        visibility: RegionVisibility::Always,
+        locked: false,
+        flags,
+        polygons,
+        bounds,
+    }
+}
@@ -0,0 +1,361 @@
+mod common;
+
+use common::*;
+use wayfinder_core::{
+    enums::{GridDiagonals, TokenDisposition, WallDirection, WallMovementType},
+    types::{
+        ElevationWindow, FindMovementPathOptions, Observer, Rectangle, RegionFlags, RegionShape, SneakOptions,
+        TokenDocument, Wall,
+    },
+};
+
+fn positions(result: &wayfinder_core::types::FindMovementPathResult) -> Vec<(f64, f64)> {
+    result.waypoints.iter().map(|waypoint| (waypoint.x, waypoint.y)).collect()
+}
+
+#[test]
+fn square_open_room_is_a_straight_line() {
+    let grid = square_grid(GridDiagonals::Equidistant);
+    let token = token("mover", 0.0, 0.0, TokenDisposition::Friendly);
+    let result = open_room().find_path(&grid, &token, &[(500.0, 0.0)], FindMovementPathOptions::default());
+
+    assert_eq!(positions(&result), vec![(0.0, 0.0), (500.0, 0.0)]);
+    assert_eq!(result.cost, 5.0);
+    assert_eq!(result.distance, 5.0);
+    assert_eq!(result.unreachable, None);
+    assert!(!result.truncated);
+}
+
+#[test]
+fn square_diagonals_follow_the_scene_rule() {
+    let token = token("mover", 0.0, 0.0, TokenDisposition::Friendly);
+
+    for (diagonals, cost) in [
+        (GridDiagonals::Equidistant, 3.0),
+        (GridDiagonals::Alternating1, 4.0),
+        (GridDiagonals::Alternating2, 5.0),
+        (GridDiagonals::Rectilinear, 6.0),
+    ] {
+        let grid = square_grid(diagonals);
+        let result = open_room().find_path(&grid, &token, &[(300.0, 300.0)], FindMovementPathOptions::default());
+
+        assert_eq!(result.cost, cost, "{diagonals:?}");
+        assert_eq!(result.waypoints.last().map(|waypoint| (waypoint.x, waypoint.y)), Some((300.0, 300.0)));
+    }
+}
+
+#[test]
+fn square_path_goes_around_walls() {
+    let scene = divided_room();
+    let grid = square_grid(GridDiagonals::Equidistant);
+    let token = token("mover", 0.0, 0.0, TokenDisposition::Friendly);
+    let result = scene.find_path(&grid, &token, &[(500.0, 0.0)], FindMovementPathOptions::default());
+
+    assert_eq!(result.waypoints.last().map(|waypoint| (waypoint.x, waypoint.y)), Some((500.0, 0.0)));
+    assert_eq!(result.cost, 19.0);
+    assert!(!scene.path_collides(&grid, &token, &result));
+}
+
+#[test]
+fn square_one_way_walls_only_block_from_one_side() {
+    let grid = square_grid(GridDiagonals::Equidistant);
+    let scene =
+        open_room().with_walls(vec![Wall { dir: WallDirection::Left, ..wall("one-way", [300.0, 0.0, 300.0, 1000.0]) }]);
+
+    // Only moving across from the right of the wall is blocked
+    let token = token("mover", 500.0, 0.0, TokenDisposition::Friendly);
+    let result = scene.find_path(&grid, &token, &[(0.0, 0.0)], FindMovementPathOptions::default());
+    assert_eq!(result.unreachable, Some(1));
+
+    let token = common::token("mover", 0.0, 0.0, TokenDisposition::Friendly);
+    let result = scene.find_path(&grid, &token, &[(500.0, 0.0)], FindMovementPathOptions::default());
+    assert_eq!(result.unreachable, None);
+    assert_eq!(positions(&result), vec![(0.0, 0.0), (500.0, 0.0)]);
+    assert_eq!(result.cost, 5.0);
+}
+
+#[test]
+fn square_path_through_several_waypoints() {
+    let grid = square_grid(GridDiagonals::Illegal);
+    let token = token("mover", 0.0, 0.0, TokenDisposition::Friendly);
+    let result =
+        open_room().find_path(&grid, &token, &[(300.0, 0.0), (300.0, 300.0)], FindMovementPathOptions::default());
+
+    assert_eq!(positions(&result), vec![(0.0, 0.0), (300.0, 0.0), (300.0, 300.0)]);
+    assert_eq!(result.segments.len(), 2);
+    assert_eq!(result.cost, 6.0);
+}
+
+#[test]
+fn square_unreachable_waypoint() {
+    let scene = closet_room();
+    let grid = square_grid(GridDiagonals::Equidistant);
+    let token = token("mover", 0.0, 0.0, TokenDisposition::Friendly);
+
+    let result = scene.find_path(&grid, &token, &[(500.0, 500.0)], FindMovementPathOptions::default());
+    assert_eq!(result.unreachable, Some(1));
+    assert_eq!(positions(&result), vec![(0.0, 0.0)]);
+
+    let options = FindMovementPathOptions { closest: true, ..Default::default() };
+    let result = scene.find_path(&grid, &token, &[(500.0, 500.0)], options);
+    let (x, y) = *positions(&result).last().unwrap();
+    assert_eq!(result.unreachable, Some(1));
+    assert!((x, y) != (500.0, 500.0));
+    assert!((x - 500.0).abs() <= 100.0 && (y - 500.0).abs() <= 100.0);
+    assert!(!scene.path_collides(&grid, &token, &result));
+}
+
+#[test]
+fn square_hostile_tokens_block_the_path() {
+    let grid = square_grid(GridDiagonals::Equidistant);
+    let token = token("mover", 0.0, 0.0, TokenDisposition::Friendly);
+
+    let scene = corridor_room().with_tokens(vec![common::token("guard", 500.0, 0.0, TokenDisposition::Hostile)]);
+    let result = scene.find_path(&grid, &token, &[(900.0, 0.0)], FindMovementPathOptions::default());
+    assert_eq!(result.unreachable, Some(1));
+
+    let options = FindMovementPathOptions { ignore_tokens: true, ..Default::default() };
+    let result = scene.find_path(&grid, &token, &[(900.0, 0.0)], options);
+    assert_eq!(result.unreachable, None);
+    assert_eq!(result.cost, 9.0);
+
+    let scene = corridor_room().with_tokens(vec![common::token("ally", 500.0, 0.0, TokenDisposition::Friendly)]);
+    let result = scene.find_path(&grid, &token, &[(900.0, 0.0)], FindMovementPathOptions::default());
+    assert_eq!(result.unreachable, None);
+    assert_eq!(result.cost, 9.0);
+}
+
+#[test]
+fn square_neutral_tokens_can_be_passed_but_not_ended_on() {
+    let grid = square_grid(GridDiagonals::Equidistant);
+    let scene = corridor_room().with_tokens(vec![common::token("bystander", 500.0, 0.0, TokenDisposition::Neutral)]);
+
+    for disposition in [TokenDisposition::Friendly, TokenDisposition::Hostile, TokenDisposition::Secret] {
+        let token = token("mover", 0.0, 0.0, disposition);
+
+        let result = scene.find_path(&grid, &token, &[(900.0, 0.0)], FindMovementPathOptions::default());
+        assert_eq!(result.unreachable, None, "{disposition:?}");
+        assert_eq!(result.cost, 9.0, "{disposition:?}");
+
+        let result = scene.find_path(&grid, &token, &[(500.0, 0.0)], FindMovementPathOptions::default());
+        assert_eq!(result.unreachable, Some(1), "{disposition:?}");
+    }
+}
+
+#[test]
+fn square_path_avoids_impassable_regions() {
+    let grid = square_grid(GridDiagonals::Equidistant);
+    let token = token("mover", 0.0, 0.0, TokenDisposition::Friendly);
+    let scene = open_room().with_regions(vec![region(
+        "chasm",
+        RegionShape::Rectangle { x: 300.0, y: 0.0, width: 100.0, height: 900.0, rotation: 0.0, hole: false },
+        RegionFlags { impassable: true, movement_cost: None, water: false },
+    )]);
+
+    let result = scene.find_path(&grid, &token, &[(500.0, 0.0)], FindMovementPathOptions::default());
+    assert_eq!(result.unreachable, None);
+    assert_eq!(result.cost, 18.0);
+}
+
+#[test]
+fn square_difficult_terrain_costs_more() {
+    let grid = square_grid(GridDiagonals::Equidistant);
+    let token = token("mover", 0.0, 0.0, TokenDisposition::Friendly);
+    let scene = open_room().with_regions(vec![region(
+        "mud",
+        RegionShape::Rectangle { x: 0.0, y: 0.0, width: 1000.0, height: 1000.0, rotation: 0.0, hole: false },
+        RegionFlags { impassable: false, movement_cost: Some(2.0), water: false },
+    )]);
+
+    let result = scene.find_path(&grid, &token, &[(500.0, 0.0)], FindMovementPathOptions::default());
+    assert_eq!(result.cost, 10.0);
+    assert_eq!(result.distance, 5.0);
+}
+
+#[test]
+fn square_walking_does_not_climb_over_low_walls() {
+    let grid = square_grid(GridDiagonals::Equidistant);
+    let token = token("mover", 0.0, 0.0, TokenDisposition::Friendly);
+    let options = FindMovementPathOptions { elevation_window: Some(ElevationWindow::Levels(2)), ..Default::default() };
+    let result = low_wall_room().find_path(&grid, &token, &[(500.0, 0.0)], options);
+
+    assert_eq!(result.unreachable, Some(1));
+    assert!(result.waypoints.iter().all(|waypoint| waypoint.elevation == 0.0));
+}
+
+#[test]
+fn square_wall_height_blocks_from_the_bottom_up_to_the_top() {
+    let grid = square_grid(GridDiagonals::Equidistant);
+    let scene = open_room().with_walls(vec![low_wall("ledge", [300.0, 0.0, 300.0, 1000.0], 5.0, 15.0)]);
+
+    // The bottom of the wall is inclusive and the top is exclusive
+    for (elevation, blocked) in [(0.0, false), (5.0, true), (10.0, true), (15.0, false), (20.0, false)] {
+        let token = TokenDocument { elevation, ..token("mover", 0.0, 0.0, TokenDisposition::Friendly) };
+        let result = scene.find_path(&grid, &token, &[(500.0, 0.0)], FindMovementPathOptions::default());
+
+        if blocked {
+            assert_eq!(result.unreachable, Some(1), "{elevation}");
+        } else {
+            assert_eq!(result.unreachable, None, "{elevation}");
+            assert_eq!(positions(&result), vec![(0.0, 0.0), (500.0, 0.0)], "{elevation}");
+            assert_eq!(result.cost, 5.0, "{elevation}");
+        }
+    }
+}
+
+#[test]
+fn square_flying_passes_over_low_walls() {
+    let grid = square_grid(GridDiagonals::Equidistant);
+    let token =
+        TokenDocument { movement_action: "fly".to_string(), ..token("mover", 0.0, 0.0, TokenDisposition::Friendly) };
+    let options = FindMovementPathOptions { elevation_window: Some(ElevationWindow::Levels(2)), ..Default::default() };
+    let result = low_wall_room().find_path(&grid, &token, &[(500.0, 0.0)], options);
+
+    assert_eq!(result.unreachable, None);
+    assert_eq!(
+        result.waypoints.last().map(|waypoint| (waypoint.x, waypoint.y, waypoint.elevation)),
+        Some((500.0, 0.0, 0.0))
+    );
+    assert!(result.waypoints.iter().any(|waypoint| waypoint.elevation >= 5.0));
+}
+
+#[test]
+fn square_search_is_truncated_by_max_nodes() {
+    let grid = square_grid(GridDiagonals::Equidistant);
+    let token = token("mover", 0.0, 0.0, TokenDisposition::Friendly);
+    let options = FindMovementPathOptions { max_nodes: Some(10), ..Default::default() };
+    let result = divided_room().find_path(&grid, &token, &[(500.0, 0.0)], options);
+
+    assert!(result.truncated);
+    assert_eq!(result.unreachable, None);
+    assert!(positions(&result).last() != Some(&(500.0, 0.0)));
+}
+
+#[test]
+fn square_exploration_coverage_checks_the_whole_token() {
+    let grid = square_grid(GridDiagonals::Equidistant);
+    let token = TokenDocument { width: 2.0, height: 2.0, ..token("mover", 0.0, 0.0, TokenDisposition::Friendly) };
+
+    // The center of the token stays inside the explored strip, but most of its bottom row doesn't
+    let scene = open_room().with_explored(vec![Rectangle::new(0.0, 0.0, 1000.0, 150.0)]);
+
+    let options = FindMovementPathOptions { use_exploration: true, ..Default::default() };
+    let result = scene.find_path(&grid, &token, &[(800.0, 0.0)], options.clone());
+    assert_eq!(result.unreachable, None);
+    assert_eq!(result.cost, 8.0);
+
+    let options = FindMovementPathOptions { use_exploration: true, exploration_coverage: Some(0.5), ..options };
+    let result = scene.find_path(&grid, &token, &[(800.0, 0.0)], options.clone());
+    assert_eq!(result.unreachable, Some(1));
+
+    let options = FindMovementPathOptions { exploration_coverage: Some(0.25), ..options };
+    let result = scene.find_path(&grid, &token, &[(800.0, 0.0)], options);
+    assert_eq!(result.unreachable, None);
+}
+
+#[test]
+fn square_sneak_avoids_spaces_observers_can_see() {
+    let grid = square_grid(GridDiagonals::Illegal);
+    let token = token("mover", 0.0, 500.0, TokenDisposition::Friendly);
+    let observer = Observer::new(500.0, 850.0, 400.0);
+    let sneak = |penalty| FindMovementPathOptions {
+        sneak: Some(SneakOptions { observers: vec![observer], penalty }),
+        ..Default::default()
+    };
+
+    let result = open_room().find_path(&grid, &token, &[(900.0, 500.0)], FindMovementPathOptions::default());
+    assert_eq!(result.cost, 9.0);
+
+    // The straight line passes in front of the observer, so the path goes around it
+    let result = open_room().find_path(&grid, &token, &[(900.0, 500.0)], sneak(None));
+    assert_eq!(result.unreachable, None);
+    assert_eq!(result.cost, 11.0);
+
+    let result = open_room().find_path(&grid, &token, &[(900.0, 500.0)], sneak(Some(10.0)));
+    assert_eq!(result.unreachable, None);
+    assert!(result.cost > 9.0);
+
+    // A wall that only blocks sight hides the straight line without getting in its way
+    let scene = open_room()
+        .with_walls(vec![Wall { r#move: WallMovementType::None, ..wall("curtain", [0.0, 700.0, 1000.0, 700.0]) }]);
+    let result = scene.find_path(&grid, &token, &[(900.0, 500.0)], sneak(None));
+    assert_eq!(result.cost, 9.0);
+}
+
+#[test]
+fn hexagonal_open_room_is_a_straight_line() {
+    // The first row is shifted left by half a hex, so its spaces start at x = -50
+    let grid = hexagonal_grid(false);
+    let token = token("mover", 50.0, 0.0, TokenDisposition::Friendly);
+    let result = open_room().find_path(&grid, &token, &[(550.0, 0.0)], FindMovementPathOptions::default());
+
+    assert_eq!(positions(&result), vec![(50.0, 0.0), (550.0, 0.0)]);
+    assert_eq!(result.cost, 5.0);
+}
+
+#[test]
+fn hexagonal_path_goes_around_walls() {
+    let scene = divided_room();
+    let grid = hexagonal_grid(false);
+    let token = token("mover", 50.0, 0.0, TokenDisposition::Friendly);
+    let result = scene.find_path(&grid, &token, &[(550.0, 0.0)], FindMovementPathOptions::default());
+
+    assert_eq!(result.unreachable, None);
+    assert_eq!(result.waypoints.last().map(|waypoint| (waypoint.x, waypoint.y)), Some((550.0, 0.0)));
+    assert!(result.cost > 5.0);
+    assert!(!scene.path_collides(&grid, &token, &result));
+}
+
+#[test]
+fn hexagonal_columns_path_goes_around_walls() {
+    let scene = divided_room();
+    let grid = hexagonal_grid(true);
+    let token = token("mover", 0.0, 50.0, TokenDisposition::Friendly);
+    let result = scene.find_path(&grid, &token, &[(520.0, 50.0)], FindMovementPathOptions::default());
+
+    assert_eq!(result.unreachable, None);
+    assert!(result.cost > 5.0);
+    assert!(!scene.path_collides(&grid, &token, &result));
+}
+
+#[test]
+fn gridless_open_room_is_a_straight_line() {
+    let grid = gridless_grid();
+    let token = token("mover", 0.0, 0.0, TokenDisposition::Friendly);
+    let result = open_room().find_path(&grid, &token, &[(500.0, 0.0)], FindMovementPathOptions::default());
+
+    assert_eq!(positions(&result), vec![(0.0, 0.0), (500.0, 0.0)]);
+    assert_eq!(result.cost, 5.0);
+}
+
+#[test]
+fn gridless_path_goes_around_walls() {
+    let scene = divided_room();
+    let grid = gridless_grid();
+    let token = token("mover", 0.0, 0.0, TokenDisposition::Friendly);
+    let result = scene.find_path(&grid, &token, &[(500.0, 0.0)], FindMovementPathOptions::default());
+
+    assert_eq!(result.unreachable, None);
+    assert_eq!(result.waypoints.last().map(|waypoint| (waypoint.x, waypoint.y)), Some((500.0, 0.0)));
+    assert!(result.cost > 18.0);
+    assert!(!scene.path_collides(&grid, &token, &result));
+}
+
+#[test]
+fn gridless_token_does_not_fit_through_a_narrow_gap() {
+    let grid = gridless_grid();
+    let token = token("mover", 0.0, 400.0, TokenDisposition::Friendly);
+    let gap = |top: f64, bottom: f64| {
+        open_room()
+            .with_walls(vec![wall("top", [300.0, 0.0, 300.0, top]), wall("bottom", [300.0, bottom, 300.0, 1000.0])])
+    };
+
+    let result = gap(445.0, 455.0).find_path(&grid, &token, &[(500.0, 400.0)], FindMovementPathOptions::default());
+    assert_eq!(result.unreachable, Some(1));
+
+    let scene = gap(380.0, 520.0);
+    let result = scene.find_path(&grid, &token, &[(500.0, 400.0)], FindMovementPathOptions::default());
+    assert_eq!(result.unreachable, None);
+    assert_eq!(result.waypoints.last().map(|waypoint| (waypoint.x, waypoint.y)), Some((500.0, 400.0)));
+    assert!(!scene.path_touches_walls(&grid, &token, &result));
+}
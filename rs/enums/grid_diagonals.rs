@@ -0,0 +1,7 @@
+use crate::{enums::GridDiagonals, traits::JsDeserialize};
+
+impl JsDeserialize for GridDiagonals {
+    fn from_js(data: impl wasm_bindgen::JsCast) -> Self {
+        GridDiagonals::try_from(i32::from_js(data)).unwrap_or_else(|value| panic!("Unknown Grid Diagonals - {value}"))
+    }
+}
@@ -1,17 +1,12 @@
-pub mod grid;
-pub mod region_visibility;
-pub mod token_shape_type;
-pub mod wall_direction;
-pub mod wall_door_state;
-pub mod wall_door_type;
-pub mod wall_movement_type;
-pub mod wall_sense_type;
+mod grid;
+mod grid_diagonals;
+mod region_visibility;
+mod token_disposition;
+mod token_shape_type;
+mod wall_direction;
+mod wall_door_state;
+mod wall_door_type;
+mod wall_movement_type;
+mod wall_sense_type;
 
-pub use self::grid::Grid;
-pub use self::region_visibility::RegionVisibility;
-pub use self::token_shape_type::TokenShapeType;
-pub use self::wall_direction::WallDirection;
-pub use self::wall_door_state::WallDoorState;
-pub use self::wall_door_type::WallDoorType;
-pub use self::wall_movement_type::WallMovementType;
-pub use self::wall_sense_type::WallSenseType;
+pub use wayfinder_core::enums::*;
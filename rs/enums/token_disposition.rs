@@ -0,0 +1,8 @@
+use crate::{enums::TokenDisposition, traits::JsDeserialize};
+
+impl JsDeserialize for TokenDisposition {
+    fn from_js(data: impl wasm_bindgen::JsCast) -> Self {
+        TokenDisposition::try_from(i32::from_js(data))
+            .unwrap_or_else(|value| panic!("Unknown Token Disposition - {value}"))
+    }
+}
@@ -1,33 +1,13 @@
 use wasm_bindgen::JsValue;
 
-use crate::traits::{JsDeserialize, JsSerialize};
-
-#[derive(Clone, Copy)]
-#[derive(Debug)]
-#[derive(PartialEq, Eq)]
-#[derive(PartialOrd, Ord)]
-pub enum TokenShapeType {
-    Ellipse1 = 0,
-    Ellipse2 = 1,
-    Trapezoid1 = 2,
-    Trapezoid2 = 3,
-    Rectangle1 = 4,
-    Rectangle2 = 5,
-}
+use crate::{
+    enums::TokenShapeType,
+    traits::{JsDeserialize, JsSerialize},
+};
 
 impl JsDeserialize for TokenShapeType {
     fn from_js(data: impl wasm_bindgen::JsCast) -> Self {
-        let value = i32::from_js(data);
-
-        match value {
-            0 => TokenShapeType::Ellipse1,
-            1 => TokenShapeType::Ellipse2,
-            2 => TokenShapeType::Trapezoid1,
-            3 => TokenShapeType::Trapezoid2,
-            4 => TokenShapeType::Rectangle1,
-            5 => TokenShapeType::Rectangle2,
-            _ => panic!("Unknown Token Shape - {value}"),
-        }
+        TokenShapeType::try_from(i32::from_js(data)).unwrap_or_else(|value| panic!("Unknown Token Shape - {value}"))
     }
 }
 
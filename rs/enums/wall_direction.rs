@@ -1,21 +1,7 @@
-#[derive(Clone, Copy)]
-#[derive(Debug)]
-#[derive(PartialEq, Eq)]
-pub enum WallDirection {
-    Both = 0,
-    Left = 1,
-    Right = 2,
-}
+use crate::{enums::WallDirection, traits::JsDeserialize};
 
-impl crate::traits::JsDeserialize for WallDirection {
+impl JsDeserialize for WallDirection {
     fn from_js(data: impl wasm_bindgen::JsCast) -> Self {
-        let value = i32::from_js(data);
-
-        match value {
-            0 => WallDirection::Both,
-            1 => WallDirection::Left,
-            2 => WallDirection::Right,
-            _ => panic!("Unknown Wall Direction - {value}"),
-        }
+        WallDirection::try_from(i32::from_js(data)).unwrap_or_else(|value| panic!("Unknown Wall Direction - {value}"))
     }
 }
@@ -1,19 +1,8 @@
-#[derive(Clone, Copy)]
-#[derive(Debug)]
-#[derive(PartialEq, Eq)]
-pub enum WallMovementType {
-    None = 0,
-    Normal = 20,
-}
+use crate::{enums::WallMovementType, traits::JsDeserialize};
 
-impl crate::traits::JsDeserialize for WallMovementType {
+impl JsDeserialize for WallMovementType {
     fn from_js(data: impl wasm_bindgen::JsCast) -> Self {
-        let value = i32::from_js(data);
-
-        match value {
-            0 => WallMovementType::None,
-            20 => WallMovementType::Normal,
-            _ => panic!("Unknown Wall Movement Type - {value}"),
-        }
+        WallMovementType::try_from(i32::from_js(data))
+            .unwrap_or_else(|value| panic!("Unknown Wall Movement Type - {value}"))
     }
 }
@@ -1,10 +1,6 @@
 pub mod fog;
-pub mod regions;
-pub mod walls;
 pub mod wayfinder;
 
-pub use self::fog::Fog;
-pub use self::regions::Regions;
-pub use self::walls::Walls;
 pub use self::wayfinder::JsWallDocument;
 pub use self::wayfinder::Wayfinder;
+pub use wayfinder_core::scene::*;
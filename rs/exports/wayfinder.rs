@@ -1,13 +1,15 @@
+use std::{cell::RefCell, rc::Rc};
 use wasm_bindgen::prelude::*;
-use web_sys::WebGl2RenderingContext;
+use web_sys::{AbortSignal, WebGl2RenderingContext};
 
 use crate::{
-    enums::Grid,
-    exports::{Fog, Regions, Walls},
-    traits::{AStar, JsDeserialize, JsDeserializeVector, JsSerialize},
+    enums::{Grid, WallRestrictionType},
+    exports::{fog, Fog, SceneState},
+    modules::task,
+    traits::{AStar, JsDeserialize, JsDeserializeVector, JsHelper, JsSerialize, Node, TokenShape},
     types::{
-        GLTexture, GridMeasurePathResult, Point, Rectangle, TokenDocument, TokenFindMovementPathWaypoint,
-        TokenMovementWaypoint,
+        FindMovementPathOptions, FindMovementPathResult, GLTexture, GridMeasurePathResult, MovementPathSearch, Point,
+        Rectangle, Region, TokenDocument, TokenFindMovementPathWaypoint, TokenMovementWaypoint, Wall,
     },
 };
 
@@ -15,7 +17,67 @@ use crate::{
 const TYPESCRIPT_CUSTOM_SECTION: &'static str = r#"
 import { TokenFindMovementPathWaypoint, TokenMovementWaypoint } from "foundry-pf2e/foundry/client/_types.mjs";
 import { Point, Rectangle } from "foundry-pf2e/foundry/common/_types.mjs";
-import { GridOffset2D } from "foundry-pf2e/foundry/common/grid/_types.mjs";"#;
+import { GridOffset2D } from "foundry-pf2e/foundry/common/grid/_types.mjs";
+
+export interface FindMovementPathSegment {
+    /** The cost of moving from the previous waypoint to this one, in scene units */
+    cost: number;
+    /** The distance between the previous waypoint and this one, in scene units */
+    distance: number;
+}
+
+export interface FindMovementPathResult {
+    waypoints: TokenMovementWaypoint[];
+    /** One segment for each waypoint after the first */
+    segments: FindMovementPathSegment[];
+    cost: number;
+    distance: number;
+    /** False if a waypoint couldn't be reached or the search was truncated */
+    complete: boolean;
+    /** The index of the waypoint that couldn't be reached */
+    unreachable: number | null;
+    /** True if the search gave up because of `maxNodes` or `timeout`, the path ends at the space closest to the waypoint it was searching for */
+    truncated: boolean;
+}
+
+export interface FindMovementPathOptions {
+    /** Limit the path to parts of the scene that have been explored */
+    useExploration?: boolean;
+    /**
+     * Check every space the token covers against the explored parts of the scene instead of only its center, a space
+     * counts as explored once at least this fraction of it has been explored
+     */
+    explorationCoverage?: number;
+    /** Ignore other tokens when finding a path */
+    ignoreTokens?: boolean;
+    /** If a waypoint can't be reached, end the path at the space closest to it instead */
+    closest?: boolean;
+    /** The most spaces the search may expand before giving up */
+    maxNodes?: number;
+    /** The most time, in milliseconds, the search may take before giving up */
+    timeout?: number;
+    /**
+     * How far the path may move above or below the elevations of the waypoints, either a number of grid spaces or a
+     * range of elevations. By default the path stays between the elevations of the waypoints.
+     */
+    elevationWindow?: number | { min?: number; max?: number };
+    /**
+     * What the cost of changing elevation is multiplied by when walking, flying, burrowing, climbing, and swimming
+     * always change elevation at the normal cost
+     */
+    verticalCost?: number;
+    /** Keep the path out of sight of other tokens */
+    sneak?: SneakOptions;
+}
+
+export interface SneakOptions {
+    /** The points the path should stay out of sight of, with how far they can see in pixels (unlimited by default) */
+    observers: { x: number; y: number; radius?: number }[];
+    /**
+     * What the cost of entering a space an observer can see is multiplied by, by default those spaces can't be entered
+     */
+    penalty?: number;
+}"#;
 
 #[wasm_bindgen]
 extern "C" {
@@ -25,6 +87,14 @@ extern "C" {
     )]
     pub type JsGrid;
 
+    #[derive(Debug)]
+    #[wasm_bindgen(typescript_type = "FindMovementPathOptions")]
+    pub type JsFindMovementPathOptions;
+
+    #[derive(Debug)]
+    #[wasm_bindgen(typescript_type = "FindMovementPathResult")]
+    pub type JsFindMovementPathResult;
+
     #[derive(Debug)]
     #[wasm_bindgen(typescript_type = "GridMeasurePathResult")]
     pub type JsGridMeasurePathResult;
@@ -68,11 +138,8 @@ extern "C" {
 
 #[wasm_bindgen]
 pub struct Wayfinder {
-    bounds: Rectangle,
-    fog: Option<Fog>,
-    grid: Grid,
-    regions: Regions,
-    walls: Walls,
+    grid: Rc<Grid>,
+    state: Rc<RefCell<SceneState>>,
 }
 
 #[wasm_bindgen]
@@ -83,13 +150,18 @@ impl Wayfinder {
         grid: JsGrid,
         wall_documents: Vec<JsWallDocument>,
         region_documents: Vec<JsRegionDocumentSource>,
+        token_documents: Vec<JsTokenDocument>,
     ) -> Wayfinder {
-        let bounds = Rectangle::from_js(bounds);
         let grid = Grid::from_js(grid);
-        let regions = Regions::new(region_documents);
-        let walls = Walls::new(bounds, wall_documents);
+        let state = SceneState::new(
+            Rectangle::from_js(bounds),
+            &grid,
+            Wall::from_js_vector(wall_documents),
+            Region::from_js_vector(region_documents),
+            TokenDocument::from_js_vector(token_documents),
+        );
 
-        Wayfinder { bounds, fog: None, grid, regions, walls }
+        Wayfinder { grid: Rc::new(grid), state: Rc::new(RefCell::new(state)) }
     }
 
     #[wasm_bindgen(js_name = updateFog)]
@@ -100,12 +172,39 @@ impl Wayfinder {
         bounds: JsRectangle,
         resolution: f64,
     ) {
-        self.fog = Some(Fog::new(gl, GLTexture::from_js(gl_texture), Rectangle::from_js(bounds), resolution))
+        self.state.borrow_mut().fog =
+            Some(fog::read_fog(gl, GLTexture::from_js(gl_texture), Rectangle::from_js(bounds), resolution))
+    }
+
+    /// Replaces the fog with a mask of the explored areas, one byte or four (RGBA) for each pixel
+    ///
+    /// Unlike `updateFog` this doesn't need a WebGL context, so the mask can come from anywhere.
+    #[wasm_bindgen(js_name = updateFogFromPixels)]
+    pub fn update_fog_from_pixels(
+        &mut self,
+        pixels: &[u8],
+        width: i32,
+        height: i32,
+        bounds: JsRectangle,
+        resolution: f64,
+    ) {
+        self.state.borrow_mut().fog =
+            Some(Fog::from_pixels(Rectangle::from_js(bounds), resolution, width, height, pixels))
+    }
+
+    /// Updates part of the fog, `rect` is the area of the scene `gl_texture` covers at the resolution of the fog
+    ///
+    /// Does nothing until the whole fog has been read with `updateFog`.
+    #[wasm_bindgen(js_name = updateFogRegion)]
+    pub fn update_fog_region(&mut self, gl: WebGl2RenderingContext, gl_texture: JsGLTexture, rect: JsRectangle) {
+        if let Some(fog) = &mut self.state.borrow_mut().fog {
+            fog::read_fog_region(fog, gl, GLTexture::from_js(gl_texture), Rectangle::from_js(rect));
+        }
     }
 
     #[wasm_bindgen(js_name = isPointExplored)]
     pub fn is_point_explored(&mut self, point: JsPoint) -> bool {
-        match &self.fog {
+        match &self.state.borrow().fog {
             Some(fog) => fog.is_point_explored(Point::from_js(point)),
             None => false,
         }
@@ -113,28 +212,82 @@ impl Wayfinder {
 
     #[wasm_bindgen(js_name = addWall)]
     pub fn add_wall(&mut self, wall_document: JsWallDocument) {
-        self.walls.add_wall(wall_document);
+        self.state.borrow_mut().walls.add_wall(Wall::from_js(wall_document));
     }
 
     #[wasm_bindgen(js_name = deleteWall)]
     pub fn delete_wall(&mut self, wall_document: JsWallDocument) {
-        self.walls.delete_wall(wall_document);
+        self.state.borrow_mut().walls.delete_wall(&wall_document.get_value::<String>("_id"));
     }
 
     #[wasm_bindgen(js_name = updateWall)]
     pub fn update_wall(&mut self, wall_document: JsWallDocument) {
-        self.walls.update_wall(wall_document);
+        self.state.borrow_mut().walls.update_wall(Wall::from_js(wall_document));
     }
 
-    #[wasm_bindgen(js_name = findMovementPath)]
-    pub async fn find_movement_path(
-        &mut self,
+    #[wasm_bindgen(js_name = addRegion)]
+    pub fn add_region(&mut self, region_document: JsRegionDocumentSource) {
+        self.state.borrow_mut().regions.add_region(Region::from_js(region_document));
+    }
+
+    #[wasm_bindgen(js_name = deleteRegion)]
+    pub fn delete_region(&mut self, region_document: JsRegionDocumentSource) {
+        self.state.borrow_mut().regions.delete_region(&region_document.get_value::<String>("_id"));
+    }
+
+    #[wasm_bindgen(js_name = updateRegion)]
+    pub fn update_region(&mut self, region_document: JsRegionDocumentSource) {
+        self.state.borrow_mut().regions.update_region(Region::from_js(region_document));
+    }
+
+    #[wasm_bindgen(js_name = addToken)]
+    pub fn add_token(&mut self, token_document: JsTokenDocument) {
+        self.state.borrow_mut().tokens.add_token(TokenDocument::from_js(token_document));
+    }
+
+    #[wasm_bindgen(js_name = deleteToken)]
+    pub fn delete_token(&mut self, token_document: JsTokenDocument) {
+        self.state.borrow_mut().tokens.delete_token(&token_document.get_value::<String>("_id"));
+    }
+
+    #[wasm_bindgen(js_name = updateToken)]
+    pub fn update_token(&mut self, token_document: JsTokenDocument) {
+        self.state.borrow_mut().tokens.update_token(TokenDocument::from_js(token_document));
+    }
+
+    /// The polygon a source at `origin` can reach, as the flat `[x0, y0, x1, y1, ...]` points of a `PIXI.Polygon`
+    ///
+    /// Only walls that restrict `senseType` are used, following Foundry's rules for limited, proximity, and distance
+    /// walls. Without a `radius` the polygon fills the scene as far as the walls allow. Throws if `senseType` isn't
+    /// one of the sense types.
+    #[wasm_bindgen(js_name = computeVisibilityPolygon, unchecked_return_type = "number[]")]
+    pub fn compute_visibility_polygon(
+        &self,
+        origin: JsPoint,
+        #[wasm_bindgen(js_name = senseType, unchecked_param_type = "\"light\" | \"sight\" | \"sound\" | \"move\"")]
+        sense_type: String,
+        radius: Option<f64>,
+    ) -> Result<JsValue, JsError> {
+        let restriction = WallRestrictionType::try_from(sense_type.as_str())
+            .map_err(|value| JsError::new(&format!("Unknown Wall Restriction Type - {value}")))?;
+        let polygon = self.state.borrow().walls.compute_visibility_polygon(Point::from_js(origin), restriction, radius);
+
+        Ok(JsSerialize::to_value(polygon.into_iter().flat_map(|point| [point.x, point.y]).collect::<Vec<f64>>()))
+    }
+
+    /// The search yields to the event loop every few thousand nodes, walls, regions, and tokens can still be updated
+    /// while it's running. If `signal` is aborted the search stops and the promise resolves to `null`.
+    #[wasm_bindgen(js_name = findMovementPath, unchecked_return_type = "Promise<FindMovementPathResult | null>")]
+    pub fn find_movement_path(
+        &self,
         token: JsTokenDocument,
         waypoints: Vec<JsTokenFindMovementPathWaypoint>,
-        use_exploration: bool,
         grid_measure_path_result: JsGridMeasurePathResult,
-    ) -> Vec<JsTokenMovementWaypoint> {
+        options: Option<JsFindMovementPathOptions>,
+        signal: Option<AbortSignal>,
+    ) -> js_sys::Promise {
         let token = TokenDocument::from_js(token);
+        let options = options.map(FindMovementPathOptions::from_js).unwrap_or_default();
         let waypoints = TokenFindMovementPathWaypoint::from_js_vector(waypoints);
         let grid_measure_path_result = GridMeasurePathResult::from_js(grid_measure_path_result);
 
@@ -145,33 +298,81 @@ impl Wayfinder {
             default_waypoint = new_waypoints.last().unwrap();
         }
 
-        let path = match &self.grid {
-            Grid::Gridless(gridless_grid) => gridless_grid.find_path(
-                new_waypoints,
-                &token,
-                &self.bounds,
-                &self.walls,
-                if use_exploration { &self.fog } else { &None },
-                &grid_measure_path_result,
-            ),
-            Grid::Square(square_grid) => square_grid.find_path(
-                new_waypoints,
-                &token,
-                &self.bounds,
-                &self.walls,
-                if use_exploration { &self.fog } else { &None },
-                &grid_measure_path_result,
-            ),
-            Grid::Hexagonal(hexagonal_grid) => hexagonal_grid.find_path(
-                new_waypoints,
-                &token,
-                &self.bounds,
-                &self.walls,
-                if use_exploration { &self.fog } else { &None },
-                &grid_measure_path_result,
-            ),
-        };
-
-        path.iter().map(|waypoint| JsSerialize::to_value(waypoint).into()).collect()
+        let grid = self.grid.clone();
+        let state = self.state.clone();
+
+        wasm_bindgen_futures::future_to_promise(async move {
+            let result = match &*grid {
+                Grid::Gridless(gridless_grid) => {
+                    run_path_search(
+                        gridless_grid,
+                        &state,
+                        token,
+                        new_waypoints,
+                        grid_measure_path_result,
+                        options,
+                        signal,
+                    )
+                    .await
+                }
+                Grid::Square(square_grid) => {
+                    run_path_search(
+                        square_grid,
+                        &state,
+                        token,
+                        new_waypoints,
+                        grid_measure_path_result,
+                        options,
+                        signal,
+                    )
+                    .await
+                }
+                Grid::Hexagonal(hexagonal_grid) => {
+                    run_path_search(
+                        hexagonal_grid,
+                        &state,
+                        token,
+                        new_waypoints,
+                        grid_measure_path_result,
+                        options,
+                        signal,
+                    )
+                    .await
+                }
+            };
+
+            Ok(match result {
+                Some(result) => JsSerialize::to_value(result.scale(grid.distance())),
+                None => JsValue::NULL,
+            })
+        })
+    }
+}
+
+async fn run_path_search<N: Node + Eq, T: TokenShape, G: AStar<N, T>>(
+    grid: &G,
+    state: &RefCell<SceneState>,
+    token: TokenDocument,
+    waypoints: Vec<TokenMovementWaypoint>,
+    grid_measure_path_result: GridMeasurePathResult,
+    options: FindMovementPathOptions,
+    signal: Option<AbortSignal>,
+) -> Option<FindMovementPathResult> {
+    let deadline = options.timeout.map(|timeout| js_sys::Date::now() + timeout);
+    let mut search =
+        MovementPathSearch::new(grid, &state.borrow(), &token, waypoints, options, &grid_measure_path_result);
+
+    loop {
+        if signal.as_ref().is_some_and(AbortSignal::aborted) {
+            return None;
+        }
+
+        // The state is only borrowed while stepping, so it can be updated while the search is waiting
+        let expired = deadline.is_some_and(|deadline| js_sys::Date::now() >= deadline);
+        if let Some(result) = search.step(grid, &state.borrow(), expired) {
+            return Some(result);
+        }
+
+        task::yield_now().await;
     }
 }
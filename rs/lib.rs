@@ -5,9 +5,7 @@ use wasm_bindgen::prelude::*;
 
 mod enums;
 mod exports;
-mod grids;
 mod modules;
-mod nodes;
 mod traits;
 mod types;
 mod utils;
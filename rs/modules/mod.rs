@@ -1,3 +1,3 @@
-pub mod geometry;
-pub mod math;
-pub mod number;
+pub mod task;
+
+pub use wayfinder_core::modules::*;
@@ -1,5 +1,5 @@
 use js_sys::Array;
-use std::fmt::Debug;
+use std::{collections::HashMap, fmt::Debug};
 use wasm_bindgen::JsCast;
 
 pub trait JsDeserialize {
@@ -26,11 +26,7 @@ impl<T: JsDeserialize + Debug> JsDeserializeVector for T {
 
 impl<T: JsDeserialize + Debug> JsDeserializeOption for T {
     fn from_js_option(data: Option<impl JsCast>) -> Option<Self> {
-        if let Some(value) = data {
-            Some(T::from_js(value))
-        } else {
-            None
-        }
+        data.map(T::from_js)
     }
 }
 
@@ -48,6 +44,18 @@ impl<T: JsDeserialize + Debug> JsDeserialize for Vec<T> {
     }
 }
 
+impl<T: JsDeserialize + Debug> JsDeserialize for HashMap<String, T> {
+    fn from_js(data: impl JsCast) -> Self {
+        js_sys::Object::entries(data.as_ref().unchecked_ref())
+            .iter()
+            .map(|entry| {
+                let entry: Array = entry.into();
+                (JsDeserialize::from_js(entry.get(0)), JsDeserialize::from_js(entry.get(1)))
+            })
+            .collect()
+    }
+}
+
 impl<T: JsDeserialize + Debug> JsDeserialize for Option<T> {
     fn from_js(data: impl JsCast) -> Self {
         if data.as_ref().is_undefined() {
@@ -14,7 +14,7 @@ impl<V: JsSerialize + Clone> JsSerialize for Vec<V> {
             array.set(i as u32, JsSerialize::to_value(v.clone()));
         }
 
-        return array.into();
+        array.into()
     }
 }
 
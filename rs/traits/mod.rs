@@ -1,18 +1,9 @@
-pub mod astar;
-pub mod base_grid;
 pub mod js_deserialize;
 pub mod js_helper;
 pub mod js_serialize;
-pub mod node;
-pub mod skip_last;
-pub mod token_shape;
 
-pub use self::astar::AStar;
-pub use self::base_grid::BaseGrid;
 pub use self::js_deserialize::JsDeserialize;
 pub use self::js_deserialize::JsDeserializeVector;
 pub use self::js_helper::JsHelper;
 pub use self::js_serialize::JsSerialize;
-pub use self::node::Node;
-pub use self::skip_last::SkipLast;
-pub use self::token_shape::TokenShape;
+pub use wayfinder_core::traits::*;
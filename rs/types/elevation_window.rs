@@ -0,0 +1,16 @@
+use crate::{
+    traits::{JsDeserialize, JsHelper},
+    types::ElevationWindow,
+};
+
+impl JsDeserialize for ElevationWindow {
+    fn from_js(data: impl wasm_bindgen::JsCast) -> Self {
+        match data.as_ref().as_f64() {
+            Some(levels) => ElevationWindow::Levels(levels as i32),
+            None => ElevationWindow::Range {
+                min: data.get("min").as_f64().unwrap_or(f64::NEG_INFINITY),
+                max: data.get("max").as_f64().unwrap_or(f64::INFINITY),
+            },
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use crate::{
+    traits::{JsDeserialize, JsHelper},
+    types::FindMovementPathOptions,
+};
+
+impl JsDeserialize for FindMovementPathOptions {
+    fn from_js(data: impl wasm_bindgen::JsCast) -> Self {
+        FindMovementPathOptions {
+            use_exploration: if data.has("useExploration") { data.get_value("useExploration") } else { false },
+            exploration_coverage: data.get_value("explorationCoverage"),
+            ignore_tokens: if data.has("ignoreTokens") { data.get_value("ignoreTokens") } else { false },
+            closest: if data.has("closest") { data.get_value("closest") } else { false },
+            max_nodes: data.get_value("maxNodes"),
+            timeout: data.get_value("timeout"),
+            elevation_window: data.get_value("elevationWindow"),
+            vertical_cost: data.get_value("verticalCost"),
+            sneak: data.get_value("sneak"),
+        }
+    }
+}
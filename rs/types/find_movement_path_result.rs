@@ -0,0 +1,39 @@
+use crate::{
+    traits::{JsHelper, JsSerialize},
+    types::{FindMovementPathResult, FindMovementPathSegment},
+};
+
+impl JsSerialize for FindMovementPathSegment {
+    fn to_value(value: Self) -> wasm_bindgen::JsValue {
+        let object = js_sys::Object::new();
+
+        object.set("cost", JsSerialize::to_value(value.cost));
+        object.set("distance", JsSerialize::to_value(value.distance));
+
+        object.into()
+    }
+}
+
+impl JsSerialize for FindMovementPathResult {
+    fn to_value(value: Self) -> wasm_bindgen::JsValue {
+        let object = js_sys::Object::new();
+        let waypoints = js_sys::Array::new();
+
+        for waypoint in &value.waypoints {
+            waypoints.push(&JsSerialize::to_value(waypoint));
+        }
+
+        object.set("waypoints", waypoints.into());
+        object.set("segments", JsSerialize::to_value(value.segments));
+        object.set("cost", JsSerialize::to_value(value.cost));
+        object.set("distance", JsSerialize::to_value(value.distance));
+        object.set("complete", JsSerialize::to_value(value.unreachable.is_none() && !value.truncated));
+        object.set("truncated", JsSerialize::to_value(value.truncated));
+        object.set(
+            "unreachable",
+            value.unreachable.map_or(wasm_bindgen::JsValue::NULL, |index| JsSerialize::to_value(index as u32)),
+        );
+
+        object.into()
+    }
+}
@@ -1,14 +1,7 @@
-use crate::traits::{JsDeserialize, JsHelper, JsSerialize};
-
-#[derive(Clone, Copy)]
-#[derive(Debug)]
-pub struct GridMeasurePathResult {
-    pub distance: f64,
-    pub cost: f64,
-    pub spaces: i32,
-    pub diagonals: i32,
-    pub euclidean: f64,
-}
+use crate::{
+    traits::{JsDeserialize, JsHelper},
+    types::GridMeasurePathResult,
+};
 
 impl JsDeserialize for GridMeasurePathResult {
     fn from_js(data: impl wasm_bindgen::JsCast) -> Self {
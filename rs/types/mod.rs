@@ -1,38 +1,20 @@
+mod elevation_window;
+mod find_movement_path_options;
+mod find_movement_path_result;
 mod gl_texture;
 mod grid_measure_path_result;
 mod grid_offset;
 mod hexagonal_grid_cube;
-mod line_intersection;
 mod point;
-mod quadtree;
-mod ray;
 mod rectangle;
 mod region;
+mod region_behavior;
+mod region_shape;
+mod sneak_options;
 mod token_document;
 mod token_find_movement_path_waypoint;
 mod token_movement_waypoint;
-mod token_shape_data;
 mod wall;
 
 pub use self::gl_texture::GLTexture;
-pub use self::grid_measure_path_result::GridMeasurePathResult;
-pub use self::grid_offset::GridOffset2D;
-pub use self::grid_offset::GridOffset3D;
-pub use self::hexagonal_grid_cube::HexagonalGridCube2D;
-pub use self::hexagonal_grid_cube::HexagonalGridCube3D;
-pub use self::line_intersection::LineIntersection;
-pub use self::point::ElevatedPoint;
-pub use self::point::Point;
-pub use self::quadtree::CollisionCheck;
-pub use self::quadtree::QuadPointer;
-pub use self::quadtree::Quadtree;
-pub use self::quadtree::QuadtreeObject;
-pub use self::ray::Ray;
-pub use self::rectangle::Rectangle;
-pub use self::region::Region;
-pub use self::token_document::TokenDocument;
-pub use self::token_find_movement_path_waypoint::TokenFindMovementPathWaypoint;
-pub use self::token_movement_waypoint::TokenMovementWaypoint;
-pub use self::token_shape_data::TokenHexagonalShapeData;
-pub use self::token_shape_data::TokenSquareShapeData;
-pub use self::wall::Wall;
+pub use wayfinder_core::types::*;
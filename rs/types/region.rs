@@ -1,15 +1,9 @@
 use crate::{
-    enums::RegionVisibility,
+    modules::geometry,
     traits::{JsDeserialize, JsHelper},
+    types::{region_shape, Region, RegionElevation, RegionFlags, RegionShape},
 };
 
-#[derive(Clone)]
-#[derive(Debug)]
-pub struct RegionElevation {
-    pub bottom: f64,
-    pub top: f64,
-}
-
 impl JsDeserialize for RegionElevation {
     fn from_js(data: impl wasm_bindgen::JsCast) -> Self {
         let bottom_value = data.get("bottom");
@@ -22,30 +16,46 @@ impl JsDeserialize for RegionElevation {
     }
 }
 
-#[derive(Clone)]
-#[derive(Debug)]
-pub struct Region {
-    pub id: String,
-    pub name: String,
-    pub color: String,
-    pub shapes: Vec<f64>,
-    pub elevation: RegionElevation,
-    pub behaviors: Vec<f64>,
-    pub visibility: RegionVisibility,
-    pub locked: bool,
+impl JsDeserialize for RegionFlags {
+    fn from_js(data: impl wasm_bindgen::JsCast) -> Self {
+        let flags = data.get("wayfinder");
+
+        if flags.is_undefined() || flags.is_null() {
+            return RegionFlags { impassable: false, movement_cost: None, water: false };
+        }
+
+        RegionFlags {
+            impassable: flags.get_value::<Option<bool>>("impassable").unwrap_or(false),
+            movement_cost: flags.get("movementCost").as_f64(),
+            water: flags.get_value::<Option<bool>>("water").unwrap_or(false),
+        }
+    }
 }
 
 impl JsDeserialize for Region {
     fn from_js(data: impl wasm_bindgen::JsCast) -> Self {
+        let shapes: Vec<RegionShape> = js_sys::try_iter(&data.get("shapes"))
+            .unwrap()
+            .unwrap()
+            .map(Result::unwrap)
+            .filter(|shape| region_shape::SHAPE_TYPES.contains(&shape.get_value::<String>("type").as_str()))
+            .map(RegionShape::from_js)
+            .collect();
+        let polygons = Region::create_polygons(&shapes);
+        let bounds = geometry::polygons_bounds(&polygons);
+
         Region {
             id: data.get_value("_id"),
             name: data.get_value("name"),
             color: data.get_value("color"),
-            shapes: data.get_value("shapes"),
+            shapes,
             elevation: data.get_value("elevation"),
             behaviors: data.get_value("behaviors"),
             visibility: data.get_value("visibility"),
             locked: data.get_value("locked"),
+            flags: data.get_value("flags"),
+            polygons,
+            bounds,
         }
     }
 }
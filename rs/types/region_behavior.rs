@@ -0,0 +1,23 @@
+use crate::{
+    traits::{JsDeserialize, JsHelper},
+    types::{RegionBehavior, RegionBehaviorSystem},
+};
+
+impl JsDeserialize for RegionBehavior {
+    fn from_js(data: impl wasm_bindgen::JsCast) -> Self {
+        let r#type: String = data.get_value("type");
+        let system = data.get("system");
+
+        RegionBehavior {
+            id: data.get_value("_id"),
+            name: data.get_value("name"),
+            system: match r#type.as_str() {
+                "modifyMovementCost" => {
+                    RegionBehaviorSystem::ModifyMovementCost { difficulties: system.get_value("difficulties") }
+                }
+                _ => RegionBehaviorSystem::Other,
+            },
+            disabled: data.get_value("disabled"),
+        }
+    }
+}
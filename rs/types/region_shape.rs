@@ -0,0 +1,47 @@
+use crate::{
+    traits::{JsDeserialize, JsHelper},
+    types::{Point, RegionShape},
+};
+
+/// The types of region shape that can be converted, shapes of any other type are left out of the region
+pub(super) const SHAPE_TYPES: [&str; 4] = ["rectangle", "circle", "ellipse", "polygon"];
+
+impl JsDeserialize for RegionShape {
+    fn from_js(data: impl wasm_bindgen::JsCast) -> Self {
+        let r#type: String = data.get_value("type");
+
+        match r#type.as_str() {
+            "rectangle" => RegionShape::Rectangle {
+                x: data.get_value("x"),
+                y: data.get_value("y"),
+                width: data.get_value("width"),
+                height: data.get_value("height"),
+                rotation: data.get_value("rotation"),
+                hole: data.get_value("hole"),
+            },
+            "circle" => RegionShape::Circle {
+                x: data.get_value("x"),
+                y: data.get_value("y"),
+                radius: data.get_value("radius"),
+                hole: data.get_value("hole"),
+            },
+            "ellipse" => RegionShape::Ellipse {
+                x: data.get_value("x"),
+                y: data.get_value("y"),
+                radius_x: data.get_value("radiusX"),
+                radius_y: data.get_value("radiusY"),
+                rotation: data.get_value("rotation"),
+                hole: data.get_value("hole"),
+            },
+            "polygon" => {
+                let coordinates: Vec<f64> = data.get_value("points");
+
+                RegionShape::Polygon {
+                    points: coordinates.chunks_exact(2).map(|c| Point::new(c[0], c[1])).collect(),
+                    hole: data.get_value("hole"),
+                }
+            }
+            _ => panic!("Unknown Region Shape - {}", r#type),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use crate::{
+    traits::{JsDeserialize, JsHelper},
+    types::{Observer, SneakOptions},
+};
+
+impl JsDeserialize for Observer {
+    fn from_js(data: impl wasm_bindgen::JsCast) -> Self {
+        Observer {
+            x: data.get_value("x"),
+            y: data.get_value("y"),
+            radius: data.get("radius").as_f64().unwrap_or(f64::INFINITY),
+        }
+    }
+}
+
+impl JsDeserialize for SneakOptions {
+    fn from_js(data: impl wasm_bindgen::JsCast) -> Self {
+        SneakOptions { observers: data.get_value("observers"), penalty: data.get("penalty").as_f64() }
+    }
+}
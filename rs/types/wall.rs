@@ -1,51 +1,46 @@
 use crate::{
-    enums::{WallDirection, WallDoorState, WallDoorType, WallMovementType, WallSenseType},
     traits::{JsDeserialize, JsHelper},
-    types::Point,
+    types::{Wall, WallFlags, WallHeight, WallThreshold},
 };
 
-use super::Rectangle;
-
-#[derive(Clone)]
-#[derive(Debug)]
-pub struct Wall {
-    pub id: String,
-    pub c: [f64; 4],
-    pub light: WallSenseType,
-    pub r#move: WallMovementType,
-    pub sight: WallSenseType,
-    pub sound: WallSenseType,
-    pub dir: WallDirection,
-    pub door: WallDoorType,
-    pub ds: WallDoorState,
-}
-
-impl Wall {
-    pub fn blocks_movement(&self) -> bool {
-        if self.door == WallDoorType::None && self.r#move == WallMovementType::Normal {
-            return true;
-        }
+impl JsDeserialize for WallFlags {
+    fn from_js(data: impl wasm_bindgen::JsCast) -> Self {
+        let flags = data.get("wayfinder");
 
-        if self.door != WallDoorType::None && self.ds != WallDoorState::Open {
-            return true;
+        if flags.is_undefined() || flags.is_null() {
+            return WallFlags { burrow: false };
         }
 
-        false
+        WallFlags { burrow: flags.get_value::<Option<bool>>("burrow").unwrap_or(false) }
     }
+}
 
-    pub fn get_a(&self) -> Point {
-        Point::new(self.c[0], self.c[1])
-    }
+impl JsDeserialize for WallHeight {
+    fn from_js(data: impl wasm_bindgen::JsCast) -> Self {
+        let height = data.get("wall-height");
 
-    pub fn get_b(&self) -> Point {
-        Point::new(self.c[2], self.c[3])
+        if height.is_undefined() || height.is_null() {
+            return WallHeight { bottom: f64::NEG_INFINITY, top: f64::INFINITY };
+        }
+
+        WallHeight {
+            bottom: height.get("bottom").as_f64().unwrap_or(f64::NEG_INFINITY),
+            top: height.get("top").as_f64().unwrap_or(f64::INFINITY),
+        }
     }
+}
 
-    pub fn get_bounds(&self) -> Rectangle {
-        let Point { x: x0, y: y0 } = self.get_a();
-        let Point { x: x1, y: y1 } = self.get_b();
+impl JsDeserialize for WallThreshold {
+    fn from_js(data: impl wasm_bindgen::JsCast) -> Self {
+        if data.as_ref().is_undefined() || data.as_ref().is_null() {
+            return WallThreshold::default();
+        }
 
-        return Rectangle::new(x0, y0, x1 - x0, y1 - y0).normalize();
+        WallThreshold {
+            light: data.get("light").as_f64(),
+            sight: data.get("sight").as_f64(),
+            sound: data.get("sound").as_f64(),
+        }
     }
 }
 
@@ -61,6 +56,9 @@ impl JsDeserialize for Wall {
             dir: data.get_value("dir"),
             door: data.get_value("door"),
             ds: data.get_value("ds"),
+            flags: data.get_value("flags"),
+            height: data.get_value("flags"),
+            threshold: data.get_value("threshold"),
         }
     }
 }
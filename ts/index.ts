@@ -5,7 +5,7 @@ import {
     TokenMeasuredMovementWaypoint,
 } from "foundry-pf2e/foundry/client/_types.mjs";
 import { Canvas } from "foundry-pf2e/foundry/client/canvas/_module.mjs";
-import { WallDocument } from "foundry-pf2e/foundry/client/documents/_module.mjs";
+import { RegionBehavior, TokenDocument, WallDocument } from "foundry-pf2e/foundry/client/documents/_module.mjs";
 import {
     DatabaseCreateOperation,
     DatabaseDeleteOperation,
@@ -21,9 +21,21 @@ declare module "foundry-pf2e" {
     interface ClientSettingsPF2e {
         get(module: "wayfinder", settings: "enablePathfinding"): boolean;
         get(module: "wayfinder", settings: "fogExploration"): boolean;
+        get(module: "wayfinder", settings: "fogExplorationCoverage"): number;
+        get(module: "wayfinder", settings: "tokenCollision"): boolean;
+        get(module: "wayfinder", settings: "elevationWindow"): number;
+        get(module: "wayfinder", settings: "verticalCost"): number;
+        get(module: "wayfinder", settings: "sneak"): boolean;
+        get(module: "wayfinder", settings: "sneakPenalty"): number;
 
         set(module: "wayfinder", setting: "enablePathfinding", value: boolean): Promise<boolean>;
         set(module: "wayfinder", setting: "fogExploration", value: boolean): Promise<boolean>;
+        set(module: "wayfinder", setting: "fogExplorationCoverage", value: number): Promise<number>;
+        set(module: "wayfinder", setting: "tokenCollision", value: boolean): Promise<boolean>;
+        set(module: "wayfinder", setting: "elevationWindow", value: number): Promise<number>;
+        set(module: "wayfinder", setting: "verticalCost", value: number): Promise<number>;
+        set(module: "wayfinder", setting: "sneak", value: boolean): Promise<boolean>;
+        set(module: "wayfinder", setting: "sneakPenalty", value: number): Promise<number>;
     }
 }
 
@@ -53,40 +65,155 @@ Hooks.once("init", async () => {
         default: true,
     });
 
+    game.settings.register("wayfinder", "fogExplorationCoverage", {
+        name: "wayfinder.settings.fogExplorationCoverage.name",
+        hint: "wayfinder.settings.fogExplorationCoverage.hint",
+        scope: "world",
+        config: true,
+        type: Number,
+        range: { min: 0, max: 100, step: 5 },
+        default: 0,
+    });
+
+    game.settings.register("wayfinder", "tokenCollision", {
+        name: "wayfinder.settings.tokenCollision.name",
+        hint: "wayfinder.settings.tokenCollision.hint",
+        scope: "world",
+        config: true,
+        type: Boolean,
+        default: true,
+    });
+
+    game.settings.register("wayfinder", "elevationWindow", {
+        name: "wayfinder.settings.elevationWindow.name",
+        hint: "wayfinder.settings.elevationWindow.hint",
+        scope: "world",
+        config: true,
+        type: Number,
+        range: { min: 0, max: 10, step: 1 },
+        default: 0,
+    });
+
+    game.settings.register("wayfinder", "verticalCost", {
+        name: "wayfinder.settings.verticalCost.name",
+        hint: "wayfinder.settings.verticalCost.hint",
+        scope: "world",
+        config: true,
+        type: Number,
+        range: { min: 1, max: 3, step: 0.5 },
+        default: 1,
+    });
+
+    game.settings.register("wayfinder", "sneak", {
+        name: "sneak",
+        scope: "user",
+        config: false,
+        type: Boolean,
+        default: false,
+    });
+
+    game.settings.register("wayfinder", "sneakPenalty", {
+        name: "wayfinder.settings.sneakPenalty.name",
+        hint: "wayfinder.settings.sneakPenalty.hint",
+        scope: "world",
+        config: true,
+        type: Number,
+        range: { min: 0, max: 10, step: 1 },
+        default: 0,
+    });
+
     await init();
 });
 
-function updateExploration() {
-    if (!canvas.wayfinder) {
-        return;
-    }
+/** The tokens with a different disposition than `token`, and how far they can see */
+function getObservers(token: TokenPF2e) {
+    return canvas.tokens.placeables
+        .filter((other) => other !== token && !other.document.hidden && other.document.disposition !== token.document.disposition)
+        .map((other) => ({
+            x: other.center.x,
+            y: other.center.y,
+            radius: other.document.sight.range ? other.document.sight.range * canvas.dimensions.distancePixels : undefined,
+        }));
+}
 
-    if (!canvas.fog.tokenVision || !canvas.fog.fogExploration) {
-        return;
-    }
+/** The area of the scene seen by vision sources since the fog was last read */
+let exploredBounds: PIXI.Rectangle | undefined;
 
+/** Renders `rect` of the fog exploration sprite to a texture at the resolution of the fog */
+function renderExploration(rect: PIXI.Rectangle, callback: (texture: PIXI.RenderTexture) => void) {
     let textureConfiguration = canvas.fog.textureConfiguration;
     let sprite = canvas.fog.sprite;
 
     let renderTexture = PIXI.RenderTexture.create({
-        width: sprite.width * textureConfiguration.resolution,
-        height: sprite.height * textureConfiguration.resolution,
+        width: rect.width * textureConfiguration.resolution,
+        height: rect.height * textureConfiguration.resolution,
     });
     let transform = new PIXI.Matrix(
         textureConfiguration.resolution,
         0,
         0,
         textureConfiguration.resolution,
-        -(sprite.x * textureConfiguration.resolution),
-        -(sprite.y * textureConfiguration.resolution)
+        -(rect.x * textureConfiguration.resolution),
+        -(rect.y * textureConfiguration.resolution)
     );
     canvas.app.renderer.render(sprite, { renderTexture, transform });
 
-    canvas.wayfinder.updateFog(
-        (canvas.app.renderer as PIXI.Renderer).gl,
-        renderTexture.baseTexture._glTextures[(canvas.app.renderer as PIXI.Renderer).CONTEXT_UID],
-        canvas.dimensions.sceneRect,
-        textureConfiguration.resolution
+    callback(renderTexture);
+    renderTexture.destroy(true);
+}
+
+function updateExploration() {
+    if (!canvas.wayfinder) {
+        return;
+    }
+
+    if (!canvas.fog.tokenVision || !canvas.fog.fogExploration) {
+        return;
+    }
+
+    let sprite = canvas.fog.sprite;
+    let renderer = canvas.app.renderer as PIXI.Renderer;
+    exploredBounds = undefined;
+
+    renderExploration(new PIXI.Rectangle(sprite.x, sprite.y, sprite.width, sprite.height), (renderTexture) =>
+        canvas.wayfinder?.updateFog(
+            renderer.gl,
+            renderTexture.baseTexture._glTextures[renderer.CONTEXT_UID],
+            canvas.dimensions.sceneRect,
+            canvas.fog.textureConfiguration.resolution
+        )
+    );
+}
+
+/** Only reads the part of the fog that vision sources have seen since it was last read */
+function updateExploredBounds() {
+    if (!canvas.wayfinder || !exploredBounds) {
+        return;
+    }
+
+    if (!canvas.fog.tokenVision || !canvas.fog.fogExploration) {
+        return;
+    }
+
+    // Snap the area to the pixels of the fog so it lines up with what was already read
+    let sprite = canvas.fog.sprite;
+    let resolution = canvas.fog.textureConfiguration.resolution;
+    let bounds = exploredBounds.fit(new PIXI.Rectangle(sprite.x, sprite.y, sprite.width, sprite.height));
+    let left = Math.floor((bounds.left - sprite.x) * resolution) / resolution + sprite.x;
+    let top = Math.floor((bounds.top - sprite.y) * resolution) / resolution + sprite.y;
+    let right = Math.ceil((bounds.right - sprite.x) * resolution) / resolution + sprite.x;
+    let bottom = Math.ceil((bounds.bottom - sprite.y) * resolution) / resolution + sprite.y;
+    let rect = new PIXI.Rectangle(left, top, right - left, bottom - top);
+
+    let renderer = canvas.app.renderer as PIXI.Renderer;
+    exploredBounds = undefined;
+
+    if (rect.width <= 0 || rect.height <= 0) {
+        return;
+    }
+
+    renderExploration(rect, (renderTexture) =>
+        canvas.wayfinder?.updateFogRegion(renderer.gl, renderTexture.baseTexture._glTextures[renderer.CONTEXT_UID], rect)
     );
 }
 
@@ -101,26 +228,42 @@ Hooks.once("ready", () => {
             options: TokenFindMovementPathOptions
         ) {
             if (game.settings.get("wayfinder", "enablePathfinding")) {
-                if (canvas.wayfinder && canvas.scene && !canvas.grid.isGridless && !options.ignoreWalls && !options.ignoreCost) {
+                if (canvas.wayfinder && canvas.scene && !options.ignoreWalls && !options.ignoreCost) {
                     let movementHistory: TokenMeasuredMovementWaypoint[] = Array.isArray(options.history)
                         ? options.history
                         : options.history
                           ? this.document.movementHistory
                           : [];
 
+                    const controller = new AbortController();
+
                     return {
                         result: undefined,
-                        promise: canvas.wayfinder?.findMovementPath(
-                            this.document,
-                            waypoints,
-                            game.settings.get("wayfinder", "fogExploration")
-                                ? !(game.user.isGM && (!this.document.sight.enabled || game.settings.get("pf2e", "gmVision"))) &&
-                                      canvas.scene.tokenVision &&
-                                      canvas.scene.fog.exploration
-                                : false,
-                            this.document.measureMovementPath(movementHistory)
-                        ),
-                        cancel: () => {},
+                        promise: canvas.wayfinder
+                            .findMovementPath(
+                                this.document,
+                                waypoints,
+                                this.document.measureMovementPath(movementHistory),
+                                {
+                                    useExploration: game.settings.get("wayfinder", "fogExploration")
+                                        ? !(game.user.isGM && (!this.document.sight.enabled || game.settings.get("pf2e", "gmVision"))) &&
+                                          canvas.scene.tokenVision &&
+                                          canvas.scene.fog.exploration
+                                        : false,
+                                    explorationCoverage: game.settings.get("wayfinder", "fogExplorationCoverage") / 100 || undefined,
+                                    ignoreTokens: !game.settings.get("wayfinder", "tokenCollision"),
+                                    elevationWindow: game.settings.get("wayfinder", "elevationWindow") || undefined,
+                                    verticalCost: game.settings.get("wayfinder", "verticalCost"),
+                                    closest: true,
+                                    timeout: 5000,
+                                    sneak: game.settings.get("wayfinder", "sneak")
+                                        ? { observers: getObservers(this), penalty: game.settings.get("wayfinder", "sneakPenalty") || undefined }
+                                        : undefined,
+                                },
+                                controller.signal
+                            )
+                            .then((result) => result?.waypoints ?? null),
+                        cancel: () => controller.abort(),
                     };
                 }
             }
@@ -131,10 +274,21 @@ Hooks.once("ready", () => {
     );
 
     canvas.fog.addEventListener("explored", function (event: Event) {
-        updateExploration();
+        updateExploredBounds();
     });
 });
 
+Hooks.on("sightRefresh", () => {
+    for (const source of canvas.effects.visionSources) {
+        if (!source.active || !source.shape) {
+            continue;
+        }
+
+        let bounds = source.shape.getBounds();
+        exploredBounds = exploredBounds ? exploredBounds.enlarge(bounds) : bounds.clone();
+    }
+});
+
 Hooks.on("getSceneControlButtons", (controls) => {
     const tokenTools = controls.tokens?.tools;
     if (tokenTools) {
@@ -156,6 +310,17 @@ Hooks.on("getSceneControlButtons", (controls) => {
                 if (active !== undefined) game.settings.set("wayfinder", "enablePathfinding", active);
             },
         };
+        tokenTools.sneak = {
+            name: "sneak",
+            order: 4,
+            title: "wayfinder.controls.sneak.title",
+            icon: "fa-solid fa-user-ninja",
+            toggle: true,
+            active: game.settings.get("wayfinder", "sneak"),
+            onChange(_event, active) {
+                if (active !== undefined) game.settings.set("wayfinder", "sneak", active);
+            },
+        };
     }
 });
 
@@ -165,7 +330,8 @@ Hooks.on("canvasReady", (canvas: Canvas) => {
             canvas.scene.dimensions.sceneRect,
             canvas.grid,
             canvas.walls.placeables.map((w) => w.document),
-            canvas.regions.placeables.map((r) => r.document._source)
+            canvas.regions.placeables.map((r) => r.document._source),
+            canvas.tokens.placeables.map((t) => t.document)
         );
 
         updateExploration();
@@ -199,8 +365,57 @@ Hooks.on("deleteWall", (document: WallDocument<Scene>, _options: DatabaseDeleteO
     foundry.documents.TokenDocument;
 });
 
-Hooks.on("createRegion", (document: RegionDocument, _options: DatabaseCreateOperation<RegionDocument<Scene>>, _userId: string) => {});
+Hooks.on("createRegion", (document: RegionDocument<Scene>, _options: DatabaseCreateOperation<RegionDocument<Scene>>, _userId: string) => {
+    if (document.parent == game.scenes.current) {
+        canvas.wayfinder?.addRegion(document._source);
+    }
+});
 
-Hooks.on("updateRegion", (document: RegionDocument, _options: DatabaseCreateOperation<RegionDocument<Scene>>, _userId: string) => {});
+Hooks.on(
+    "updateRegion",
+    (document: RegionDocument<Scene>, _change: object, _options: DatabaseUpdateOperation<RegionDocument<Scene>>, _userId: string) => {
+        if (document.parent == game.scenes.current) {
+            canvas.wayfinder?.updateRegion(document._source);
+        }
+    }
+);
+
+Hooks.on("deleteRegion", (document: RegionDocument<Scene>, _options: DatabaseDeleteOperation<RegionDocument<Scene>>, _userId: string) => {
+    if (document.parent == game.scenes.current) {
+        canvas.wayfinder?.deleteRegion(document._source);
+    }
+});
+
+function updateRegionBehavior(document: RegionBehavior) {
+    const region = document.parent;
+    if (region && region.parent == game.scenes.current) {
+        canvas.wayfinder?.updateRegion(region._source);
+    }
+}
+
+Hooks.on("createRegionBehavior", (document: RegionBehavior) => updateRegionBehavior(document));
+
+Hooks.on("updateRegionBehavior", (document: RegionBehavior) => updateRegionBehavior(document));
+
+Hooks.on("deleteRegionBehavior", (document: RegionBehavior) => updateRegionBehavior(document));
 
-Hooks.on("deleteRegion", (document: RegionDocument, _options: DatabaseCreateOperation<RegionDocument<Scene>>, _userId: string) => {});
+Hooks.on("createToken", (document: TokenDocument<Scene>, _options: DatabaseCreateOperation<TokenDocument<Scene>>, _userId: string) => {
+    if (document.parent == game.scenes.current) {
+        canvas.wayfinder?.addToken(document);
+    }
+});
+
+Hooks.on(
+    "updateToken",
+    (document: TokenDocument<Scene>, _change: object, _options: DatabaseUpdateOperation<TokenDocument<Scene>>, _userId: string) => {
+        if (document.parent == game.scenes.current) {
+            canvas.wayfinder?.updateToken(document);
+        }
+    }
+);
+
+Hooks.on("deleteToken", (document: TokenDocument<Scene>, _options: DatabaseDeleteOperation<TokenDocument<Scene>>, _userId: string) => {
+    if (document.parent == game.scenes.current) {
+        canvas.wayfinder?.deleteToken(document);
+    }
+});